@@ -0,0 +1,42 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use library_stdnums::validate;
+use library_stdnums::{Normalize, Valid, ISBN};
+
+const ISBNS: [&str; 10] = [
+    "0139381430",
+    "0-8044-2957-X",
+    "9781449373320",
+    "978-0-306-40615-7",
+    "ISBN: 978-0-306-40615-7",
+    "ABC0139381430",
+    "0139381432",
+    "9781449373322",
+    "013938143",
+    "Bad ISBN",
+];
+
+fn valid_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("isbn_valid");
+    for i in ISBNS.iter() {
+        group.bench_with_input(BenchmarkId::new("ISBN", i), i, |b, i| {
+            b.iter(|| ISBN::new(&**i).valid())
+        });
+        group.bench_with_input(BenchmarkId::new("bytes", i), i, |b, i| {
+            b.iter(|| validate::isbn_valid(i.as_bytes()))
+        });
+    }
+    group.finish();
+}
+
+fn normalize_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("isbn_normalize");
+    for i in ISBNS.iter() {
+        group.bench_with_input(BenchmarkId::new("ISBN", i), i, |b, i| {
+            b.iter(|| ISBN::new(&**i).normalize())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(isbn_benches, valid_benchmark, normalize_benchmark);
+criterion_main!(isbn_benches);
@@ -0,0 +1,40 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use library_stdnums::validate;
+use library_stdnums::{Normalize, Valid, ISSN};
+
+const ISSNS: [&str; 8] = [
+    "0378-5955",
+    "03785955",
+    "1043-383x",
+    "1043-383X",
+    "0193-451X",
+    "019X-4511",
+    "abcdefg",
+    "XXXX-XXXX",
+];
+
+fn valid_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("issn_valid");
+    for i in ISSNS.iter() {
+        group.bench_with_input(BenchmarkId::new("ISSN", i), i, |b, i| {
+            b.iter(|| ISSN::new(&**i).valid())
+        });
+        group.bench_with_input(BenchmarkId::new("bytes", i), i, |b, i| {
+            b.iter(|| validate::issn_valid(i.as_bytes()))
+        });
+    }
+    group.finish();
+}
+
+fn normalize_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("issn_normalize");
+    for i in ISSNS.iter() {
+        group.bench_with_input(BenchmarkId::new("ISSN", i), i, |b, i| {
+            b.iter(|| ISSN::new(&**i).normalize())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(issn_benches, valid_benchmark, normalize_benchmark);
+criterion_main!(issn_benches);
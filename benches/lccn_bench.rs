@@ -1,6 +1,6 @@
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use library_stdnums::lccn::LCCN;
-use library_stdnums::traits::Valid;
+use library_stdnums::validate;
+use library_stdnums::{Valid, LCCN};
 
 fn valid_benchmark(c: &mut Criterion) {
     let lccns = [
@@ -24,6 +24,9 @@ fn valid_benchmark(c: &mut Criterion) {
         group.bench_with_input(BenchmarkId::new("char", i), i, |b, i| {
             b.iter(|| LCCN::new(&**i).valid())
         });
+        group.bench_with_input(BenchmarkId::new("bytes", i), i, |b, i| {
+            b.iter(|| validate::lccn_valid(i.as_bytes()))
+        });
     }
     group.finish();
 }
@@ -0,0 +1,23 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use library_stdnums::{CompactISBN, CompactISSN, CompactLCCN};
+
+fuzz_target!(|input: (&str, u64)| {
+    let (identifier, value) = input;
+    if let Some(isbn) = CompactISBN::new(identifier) {
+        assert_eq!(CompactISBN::from_u64(isbn.get()), Some(isbn));
+    }
+    if let Some(issn) = CompactISSN::new(identifier) {
+        assert_eq!(CompactISSN::from_u32(issn.get()), Some(issn));
+    }
+    if let Some(lccn) = CompactLCCN::new(identifier) {
+        assert_eq!(CompactLCCN::from_u64(lccn.get()), Some(lccn));
+    }
+    let _ = CompactISBN::from_u64(value).map(|isbn| isbn.to_string());
+    let _ = CompactISSN::from_u32(value as u32).map(|issn| issn.to_string());
+    let _ = CompactLCCN::from_u64(value).map(|lccn| lccn.to_string());
+    let _ = identifier.parse::<CompactISBN>();
+    let _ = identifier.parse::<CompactISSN>();
+    let _ = identifier.parse::<CompactLCCN>();
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use library_stdnums::csv::ColumnNormalizer;
+use library_stdnums::IdentifierKind;
+
+fuzz_target!(|input: &[u8]| {
+    let mut output = Vec::new();
+    let _ = ColumnNormalizer::new("isbn", IdentifierKind::ISBN).normalize(input, &mut output);
+    let _ = ColumnNormalizer::new(0, IdentifierKind::LCCN).tsv().has_headers(false).normalize(input, &mut output);
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use library_stdnums::{Identifier, Normalize, ParseOptions, Valid, ISBN};
+
+fuzz_target!(|input: &str| {
+    let isbn = ISBN::new(input);
+    let _ = (isbn.valid(), isbn.normalize(), isbn.checkdigit(), isbn.convert_to_13(), isbn.convert_to_10());
+    let _ = (isbn.parts(), isbn.display(), isbn.uri());
+    if let Some(normalized) = isbn.normalize() {
+        assert_eq!(ISBN::new(normalized.as_str()).normalize(), Some(normalized));
+    }
+    let _ = input.parse::<ISBN>();
+    for options in [ParseOptions::strict(), ParseOptions::standard(), ParseOptions::lenient()] {
+        let _ = ISBN::parse_with(input, &options);
+    }
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use library_stdnums::{Identifier, Normalize, ParseOptions, Valid, ISSN};
+
+fuzz_target!(|input: &str| {
+    let issn = ISSN::new(input);
+    let _ = (issn.valid(), issn.normalize(), issn.checkdigit());
+    let _ = (issn.parts(), issn.display(), issn.uri());
+    if let Some(normalized) = issn.normalize() {
+        assert_eq!(ISSN::new(normalized.as_str()).normalize(), Some(normalized));
+    }
+    let _ = input.parse::<ISSN>();
+    for options in [ParseOptions::strict(), ParseOptions::standard(), ParseOptions::lenient()] {
+        let _ = ISSN::parse_with(input, &options);
+    }
+});
@@ -0,0 +1,20 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use library_stdnums::{Identifier, Normalize, ParseOptions, Valid, LCCN};
+
+fuzz_target!(|input: &str| {
+    let lccn = LCCN::new(input);
+    let _ = (lccn.valid(), lccn.normalize(), lccn.marc_010(), lccn.validate_strict_as_of(2024));
+    let _ = (lccn.parts(), lccn.display(), lccn.uri());
+    if let Some(normalized) = lccn.normalize() {
+        assert_eq!(LCCN::new(normalized.as_str()).normalize(), Some(normalized));
+    }
+    if let Ok(lccn) = LCCN::from_marc_010(input) {
+        let _ = lccn.normalize();
+    }
+    let _ = input.parse::<LCCN>();
+    for options in [ParseOptions::strict(), ParseOptions::standard(), ParseOptions::lenient()] {
+        let _ = LCCN::parse_with(input, &options);
+    }
+});
@@ -0,0 +1,22 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use library_stdnums::marc::{Reader, Record};
+
+fuzz_target!(|input: &[u8]| {
+    if let Ok(mut record) = Record::from_iso2709(input) {
+        let _ = (record.control_number(), record.identifiers());
+        record.clean_identifiers();
+        // Cleaning can lengthen a field past what ISO 2709 can hold. Writing
+        // recalculates the lengths in the leader, so only the fields round trip
+        if let Ok(bytes) = record.to_iso2709() {
+            let written = Record::from_iso2709(&bytes).unwrap();
+            assert_eq!(written.fields, record.fields);
+        }
+    }
+    for record in Reader::new(input) {
+        if record.is_err() {
+            break;
+        }
+    }
+});
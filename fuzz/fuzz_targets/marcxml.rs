@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use library_stdnums::marc::xml::Reader;
+
+fuzz_target!(|input: &[u8]| {
+    for record in Reader::new(input) {
+        match record {
+            Ok(record) => {
+                let _ = record.identifiers();
+            }
+            Err(_) => break,
+        }
+    }
+});
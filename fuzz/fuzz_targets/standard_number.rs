@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use library_stdnums::{unicode, IdentifierKind, Normalize, StandardNumber};
+
+fuzz_target!(|input: &str| {
+    let _ = StandardNumber::detect(input);
+    for candidate in StandardNumber::candidates(input) {
+        assert!((0.0..=1.0).contains(&candidate.confidence));
+        let _ = candidate.number.normalize();
+    }
+    for kind in [IdentifierKind::ISBN, IdentifierKind::ISSN, IdentifierKind::LCCN] {
+        let _ = StandardNumber::new(kind, input).normalize();
+        let _ = StandardNumber::parse(kind, input);
+    }
+    let _ = unicode::clean(input);
+});
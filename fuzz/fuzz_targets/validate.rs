@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use library_stdnums::validate;
+
+fuzz_target!(|input: &[u8]| {
+    let _ = (validate::isbn_check_digit(input), validate::isbn_valid(input));
+    let _ = (validate::isbn_to_13(input), validate::isbn_to_10(input));
+    let _ = (validate::issn_check_digit(input), validate::issn_valid(input), validate::issn_normalize(input));
+    let _ = (validate::lccn_valid(input), validate::lccn_normalize(input));
+});
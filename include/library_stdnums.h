@@ -0,0 +1,74 @@
+#ifndef LIBRARY_STDNUMS_H
+#define LIBRARY_STDNUMS_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Whether the string is a valid ISBN-10 or ISBN-13
+//
+// # Safety
+//
+// `identifier` must be NULL or point to a NUL-terminated string.
+bool stdnum_isbn_valid(const char *identifier);
+
+// The ISBN-13 form of a valid ISBN, or NULL
+//
+// # Safety
+//
+// `identifier` must be NULL or point to a NUL-terminated string.
+char *stdnum_isbn_normalize(const char *identifier);
+
+// Converts a valid ISBN to ISBN-13, or returns NULL
+//
+// # Safety
+//
+// `identifier` must be NULL or point to a NUL-terminated string.
+char *stdnum_isbn_convert_to_13(const char *identifier);
+
+// Converts a valid ISBN to ISBN-10, or returns NULL if it has no ISBN-10 form
+//
+// # Safety
+//
+// `identifier` must be NULL or point to a NUL-terminated string.
+char *stdnum_isbn_convert_to_10(const char *identifier);
+
+// Whether the string is a valid ISSN
+//
+// # Safety
+//
+// `identifier` must be NULL or point to a NUL-terminated string.
+bool stdnum_issn_valid(const char *identifier);
+
+// The eight character form of a valid ISSN, or NULL
+//
+// # Safety
+//
+// `identifier` must be NULL or point to a NUL-terminated string.
+char *stdnum_issn_normalize(const char *identifier);
+
+// Whether the string is a valid LCCN
+//
+// # Safety
+//
+// `identifier` must be NULL or point to a NUL-terminated string.
+bool stdnum_lccn_valid(const char *identifier);
+
+// The normalized form of a valid LCCN, or NULL
+//
+// # Safety
+//
+// `identifier` must be NULL or point to a NUL-terminated string.
+char *stdnum_lccn_normalize(const char *identifier);
+
+// Frees a string returned by this library. Passing NULL does nothing.
+//
+// # Safety
+//
+// `string` must be NULL or a pointer returned by this library that has not been freed.
+void stdnum_string_free(char *string);
+
+#endif  /* LIBRARY_STDNUMS_H */
@@ -0,0 +1,170 @@
+//! Validation and normalization of many identifiers at once
+//!
+//! Results come back in the same order as the inputs, together with a
+//! [`Summary`] of how many were valid, invalid or converted. With the `rayon`
+//! feature, [`par_normalize`] and [`par_validate`] spread the work over all cores.
+//!
+//! ```
+//! use library_stdnums::{batch, ISBN};
+//!
+//! let normalized = batch::normalize::<ISBN, _>(["0-306-40615-2", "9780306406157", "bad"]);
+//! assert_eq!(normalized.results, vec![Some("9780306406157".to_string()), Some("9780306406157".to_string()), None]);
+//! assert_eq!(normalized.summary, batch::Summary { valid: 2, invalid: 1, converted: 1 });
+//! ```
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::traits::Identifier;
+
+/// Counts of the outcomes of a batch
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Summary {
+    /// Inputs that were valid identifiers
+    pub valid: usize,
+    /// Inputs that were not valid identifiers
+    pub invalid: usize,
+    /// Valid inputs whose normalized form differs from the input
+    pub converted: usize,
+}
+
+impl Summary {
+    pub(crate) fn record(&mut self, input: &str, normalized: Option<&str>) {
+        match normalized {
+            Some(normalized) => {
+                self.valid += 1;
+                if normalized != input {
+                    self.converted += 1;
+                }
+            }
+            None => self.invalid += 1,
+        }
+    }
+}
+
+/// The normalized forms of a batch of inputs, in input order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalized {
+    /// The normalized form of each input, or None if it was invalid
+    pub results: Vec<Option<String>>,
+    pub summary: Summary,
+}
+
+/// The validity of a batch of inputs, in input order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Validated {
+    pub results: Vec<bool>,
+    pub summary: Summary,
+}
+
+/// Normalizes every input as an identifier of type `T`
+pub fn normalize<T, S>(inputs: impl IntoIterator<Item = S>) -> Normalized
+where
+    T: Identifier,
+    S: AsRef<str>,
+{
+    let mut summary = Summary::default();
+    let results = inputs
+        .into_iter()
+        .map(|input| {
+            let normalized = normalize_one::<T>(input.as_ref());
+            summary.record(input.as_ref(), normalized.as_deref());
+            normalized
+        })
+        .collect();
+    Normalized { results, summary }
+}
+
+/// Validates every input as an identifier of type `T`
+///
+/// ```
+/// use library_stdnums::{batch, ISSN};
+///
+/// let validated = batch::validate::<ISSN, _>(vec!["0378-5955", "0378-5951"]);
+/// assert_eq!(validated.results, vec![true, false]);
+/// assert_eq!(validated.summary.invalid, 1);
+/// ```
+pub fn validate<T, S>(inputs: impl IntoIterator<Item = S>) -> Validated
+where
+    T: Identifier,
+    S: AsRef<str>,
+{
+    let normalized = normalize::<T, S>(inputs);
+    Validated {
+        results: normalized.results.iter().map(Option::is_some).collect(),
+        summary: normalized.summary,
+    }
+}
+
+/// Normalizes every input as an identifier of type `T`, in parallel
+#[cfg(feature = "rayon")]
+pub fn par_normalize<T, S>(inputs: &[S]) -> Normalized
+where
+    T: Identifier,
+    S: AsRef<str> + Sync,
+{
+    use rayon::prelude::*;
+
+    let results: Vec<Option<String>> = inputs
+        .par_iter()
+        .map(|input| normalize_one::<T>(input.as_ref()))
+        .collect();
+    let mut summary = Summary::default();
+    for (input, normalized) in inputs.iter().zip(&results) {
+        summary.record(input.as_ref(), normalized.as_deref());
+    }
+    Normalized { results, summary }
+}
+
+/// Validates every input as an identifier of type `T`, in parallel
+#[cfg(feature = "rayon")]
+pub fn par_validate<T, S>(inputs: &[S]) -> Validated
+where
+    T: Identifier,
+    S: AsRef<str> + Sync,
+{
+    let normalized = par_normalize::<T, S>(inputs);
+    Validated {
+        results: normalized.results.iter().map(Option::is_some).collect(),
+        summary: normalized.summary,
+    }
+}
+
+fn normalize_one<T: Identifier>(input: &str) -> Option<String> {
+    T::parse(input).ok()?.canonical()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ISBN, ISSN, LCCN};
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn it_normalizes_in_order() {
+        let normalized = normalize::<LCCN, _>(vec!["85-2".to_string(), "n78890351".to_string(), "".to_string()]);
+        assert_eq!(normalized.results, vec![Some("85000002".to_string()), Some("n78890351".to_string()), None]);
+        assert_eq!(normalized.summary, Summary { valid: 2, invalid: 1, converted: 1 });
+    }
+
+    #[test]
+    fn it_summarizes_an_empty_batch() {
+        let validated = validate::<ISSN, &str>([]);
+        assert!(validated.results.is_empty());
+        assert_eq!(validated.summary, Summary::default());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn it_matches_the_sequential_results_in_parallel() {
+        let inputs: Vec<String> = (0..1000).map(|n| format!("0-306-4{:04}-2", n)).collect();
+        assert_eq!(par_normalize::<ISBN, _>(&inputs), normalize::<ISBN, _>(&inputs));
+        assert_eq!(par_validate::<ISBN, _>(&inputs), validate::<ISBN, _>(&inputs));
+    }
+
+    #[test]
+    fn it_counts_converted_isbns() {
+        let validated = validate::<ISBN, _>(["0-306-40615-2", "9780306406157"]);
+        assert_eq!(validated.summary, Summary { valid: 2, invalid: 0, converted: 1 });
+    }
+}
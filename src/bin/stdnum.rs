@@ -0,0 +1,294 @@
+//! Validate and normalize ISBNs, ISSNs and LCCNs from the command line
+//!
+//! Identifiers are read from the arguments, from a file with `--file`, or from
+//! standard input, one per line. The exit status is 0 if every identifier was
+//! processed, 1 if any was invalid, and 2 for usage or input errors.
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use library_stdnums::{label, unicode, validate, Identifier, IdentifierKind, Normalize, StandardNumber, Valid, ISBN, ISSN, LCCN};
+
+#[derive(Parser)]
+#[command(name = "stdnum", version, about = "Validate and normalize ISBNs, ISSNs and LCCNs")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Format::Text, global = true)]
+    format: Format,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Report whether each identifier is valid
+    Validate(KindArgs),
+    /// Print the normalized form of each identifier
+    Normalize(KindArgs),
+    /// Convert ISBNs to ISBN-13
+    Convert(InputArgs),
+    /// Calculate the check digit of each identifier
+    Checkdigit(KindArgs),
+    /// Print ISBNs and ISSNs with hyphens
+    Hyphenate(KindArgs),
+    /// Report the most likely kind of each identifier
+    Detect(InputArgs),
+}
+
+#[derive(clap::Args)]
+struct KindArgs {
+    /// The kind of identifier, detected from each input if omitted
+    #[arg(short, long, value_enum)]
+    kind: Option<Kind>,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(clap::Args)]
+struct InputArgs {
+    /// Identifiers to process, read from standard input if none are given
+    identifiers: Vec<String>,
+
+    /// Read identifiers from a file, one per line
+    #[arg(long, conflicts_with = "identifiers")]
+    file: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Kind {
+    Isbn,
+    Issn,
+    Lccn,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+enum Format {
+    /// The result of each identifier, or an empty line if there is none
+    Text,
+    /// Input, kind, result and status, separated by tabs
+    Tsv,
+    /// One JSON object per line
+    Json,
+}
+
+type Operation = Box<dyn Fn(&str) -> Row>;
+
+/// The outcome for one input line
+#[derive(Debug, PartialEq)]
+struct Row {
+    input: String,
+    kind: Option<IdentifierKind>,
+    result: Option<String>,
+    ok: bool,
+    /// Only set by detection
+    confidence: Option<f32>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let (operation, input): (Operation, &InputArgs) = match &cli.command {
+        Command::Validate(args) => (validation(args.kind), &args.input),
+        Command::Normalize(args) => (operation(args.kind, |number| number.normalize()), &args.input),
+        Command::Convert(input) => (Box::new(convert_one), input),
+        Command::Checkdigit(args) => (operation(args.kind, checkdigit_one), &args.input),
+        Command::Hyphenate(args) => (operation(args.kind, hyphenate_one), &args.input),
+        Command::Detect(input) => (Box::new(detect_one), input),
+    };
+    match run(&*operation, input, cli.format) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(error) => {
+            eprintln!("stdnum: {}", error);
+            ExitCode::from(2)
+        }
+    }
+}
+
+// Processes every input, returning whether all of them were ok
+fn run(operation: &dyn Fn(&str) -> Row, input: &InputArgs, format: Format) -> io::Result<bool> {
+    let stdout = io::stdout();
+    let mut output = BufWriter::new(stdout.lock());
+    let mut all_ok = true;
+    let mut process = |line: &str| -> io::Result<()> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(());
+        }
+        let row = operation(line);
+        all_ok &= row.ok;
+        writeln!(output, "{}", format_row(&row, format))
+    };
+    if !input.identifiers.is_empty() {
+        for identifier in &input.identifiers {
+            process(identifier)?;
+        }
+    } else if let Some(path) = &input.file {
+        for line in BufReader::new(File::open(path)?).lines() {
+            process(&line?)?;
+        }
+    } else {
+        for line in io::stdin().lock().lines() {
+            process(&line?)?;
+        }
+    }
+    output.flush()?;
+    Ok(all_ok)
+}
+
+// An operation on identifiers of the given kind, or of the detected kind
+fn operation(kind: Option<Kind>, apply: fn(&StandardNumber) -> Option<String>) -> Operation {
+    Box::new(move |input| match identify(input, kind) {
+        Some(number) => {
+            let result = apply(&number);
+            Row { input: input.to_string(), kind: Some(number.kind()), ok: result.is_some(), result, confidence: None }
+        }
+        None => Row { input: input.to_string(), kind: None, result: None, ok: false, confidence: None },
+    })
+}
+
+fn identify(input: &str, kind: Option<Kind>) -> Option<StandardNumber> {
+    match kind {
+        Some(Kind::Isbn) => Some(StandardNumber::ISBN(ISBN::new(input))),
+        Some(Kind::Issn) => Some(StandardNumber::ISSN(ISSN::new(input))),
+        Some(Kind::Lccn) => Some(StandardNumber::LCCN(LCCN::new(input))),
+        None => StandardNumber::detect(input)
+            .or_else(|| StandardNumber::candidates(input).into_iter().next())
+            .map(|candidate| candidate.number),
+    }
+}
+
+// Validation reports invalid identifiers too, rather than leaving the result empty
+fn validation(kind: Option<Kind>) -> Operation {
+    let validate = operation(kind, |number| number.valid().then(|| "valid".to_string()));
+    Box::new(move |input| {
+        let mut row = validate(input);
+        row.result.get_or_insert_with(|| "invalid".to_string());
+        row
+    })
+}
+
+// Check digits are calculated from the input, so an invalid check digit can be corrected
+fn checkdigit_one(number: &StandardNumber) -> Option<String> {
+    let check_digit = match number {
+        StandardNumber::ISBN(isbn) => isbn.checkdigit(),
+        StandardNumber::ISSN(issn) => {
+            // Cleaned as ISSN does, but ISSN::checkdigit answers for any input
+            let cleaned = unicode::clean(&issn.identifier);
+            let cleaned = label::strip(IdentifierKind::ISSN, &cleaned);
+            validate::issn_check_digit(cleaned.as_bytes()).map(char::from)
+        }
+        StandardNumber::LCCN(_) => None,
+    };
+    check_digit.map(String::from)
+}
+
+fn hyphenate_one(number: &StandardNumber) -> Option<String> {
+    match number {
+        StandardNumber::ISBN(isbn) => isbn.display(),
+        StandardNumber::ISSN(issn) => issn.display(),
+        StandardNumber::LCCN(_) => None,
+    }
+}
+
+fn convert_one(input: &str) -> Row {
+    let result = ISBN::new(input).convert_to_13();
+    Row { input: input.to_string(), kind: Some(IdentifierKind::ISBN), ok: result.is_some(), result, confidence: None }
+}
+
+fn detect_one(input: &str) -> Row {
+    let candidate = StandardNumber::detect(input);
+    let kind = candidate.as_ref().map(|candidate| candidate.number.kind());
+    Row {
+        input: input.to_string(),
+        kind,
+        result: kind.map(|kind| kind.to_string()),
+        ok: candidate.is_some(),
+        confidence: candidate.map(|candidate| candidate.confidence),
+    }
+}
+
+fn format_row(row: &Row, format: Format) -> String {
+    let kind = row.kind.map(|kind| kind.to_string());
+    match format {
+        Format::Text => match row.confidence {
+            Some(confidence) => format!("{}\t{:.2}", row.result.as_deref().unwrap_or(""), confidence),
+            None => row.result.clone().unwrap_or_default(),
+        },
+        Format::Tsv => {
+            let mut line = format!(
+                "{}\t{}\t{}\t{}",
+                row.input,
+                kind.unwrap_or_default(),
+                row.result.as_deref().unwrap_or(""),
+                if row.ok { "ok" } else { "invalid" }
+            );
+            if let Some(confidence) = row.confidence {
+                line.push_str(&format!("\t{:.2}", confidence));
+            }
+            line
+        }
+        Format::Json => {
+            let mut object = serde_json::json!({
+                "input": row.input,
+                "kind": kind,
+                "result": row.result,
+                "ok": row.ok,
+            });
+            if let Some(confidence) = row.confidence {
+                object["confidence"] = confidence.into();
+            }
+            object.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(input: &str, kind: Option<IdentifierKind>, result: Option<&str>, ok: bool) -> Row {
+        Row { input: input.to_string(), kind, result: result.map(str::to_string), ok, confidence: None }
+    }
+
+    #[test]
+    fn it_applies_operations_to_the_detected_kind() {
+        let normalize = operation(None, |number| number.normalize());
+        assert_eq!(normalize("0-306-40615-2"), row("0-306-40615-2", Some(IdentifierKind::ISBN), Some("9780306406157"), true));
+        assert_eq!(normalize("Bad number"), row("Bad number", None, None, false));
+        let validate = validation(Some(Kind::Lccn));
+        assert_eq!(validate("n78-890351"), row("n78-890351", Some(IdentifierKind::LCCN), Some("valid"), true));
+        assert_eq!(validate("zzz"), row("zzz", Some(IdentifierKind::LCCN), Some("invalid"), false));
+        let hyphenate = operation(Some(Kind::Issn), hyphenate_one);
+        assert_eq!(hyphenate("1043383x").result.unwrap(), "1043-383X");
+    }
+
+    #[test]
+    fn it_calculates_check_digits_of_invalid_identifiers() {
+        let checkdigit = operation(Some(Kind::Isbn), checkdigit_one);
+        assert_eq!(checkdigit("978-0-306-40615-2").result.unwrap(), "7");
+        let checkdigit = operation(Some(Kind::Issn), checkdigit_one);
+        assert_eq!(checkdigit("0193-4510").result.unwrap(), "1");
+        assert_eq!(checkdigit("ISSN 0378-5955").result.unwrap(), "5");
+        assert_eq!(checkdigit("０３７８－５９５").result.unwrap(), "5");
+        assert_eq!(checkdigit("bad").result, None);
+        assert_eq!(checkdigit("ab123-4567").result, None);
+        assert_eq!(checkdigit("٠٣٧٨-٥٩٥").result.unwrap(), "5");
+        assert_eq!(checkdigit("ⅰ234567").result, None);
+    }
+
+    #[test]
+    fn it_formats_rows() {
+        let converted = convert_one("0-306-40615-2");
+        assert_eq!(format_row(&converted, Format::Text), "9780306406157");
+        assert_eq!(format_row(&converted, Format::Tsv), "0-306-40615-2\tISBN\t9780306406157\tok");
+        assert_eq!(
+            format_row(&convert_one("bad"), Format::Json),
+            r#"{"input":"bad","kind":"ISBN","ok":false,"result":null}"#
+        );
+        assert_eq!(format_row(&detect_one("0378-5955"), Format::Text), "ISSN\t0.90");
+    }
+}
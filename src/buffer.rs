@@ -0,0 +1,103 @@
+use core::fmt;
+use core::ops::Deref;
+
+/// An ASCII string of at most `N` bytes, stored inline without allocating
+///
+/// Returned by the functions in [`validate`](crate::validate) and the compact
+/// types, so that normalized identifiers are available without an allocator.
+///
+/// ```
+/// use library_stdnums::validate;
+/// let isbn13 = validate::isbn_to_13(b"0-306-40615-2").unwrap();
+/// assert_eq!(isbn13, "9780306406157");
+/// assert_eq!(isbn13.len(), 13);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FixedStr<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FixedStr<N> {
+    pub(crate) const fn new() -> Self {
+        FixedStr { bytes: [0; N], len: 0 }
+    }
+
+    /// Appends an ASCII byte, returning false if the byte is not ASCII or the string is full
+    pub(crate) const fn push(&mut self, byte: u8) -> bool {
+        if !byte.is_ascii() || self.len == N {
+            return false;
+        }
+        self.bytes[self.len] = byte;
+        self.len += 1;
+        true
+    }
+
+    // Only for bytes known to be ASCII and to fit
+    pub(crate) const fn from_bytes(bytes: &[u8]) -> Self {
+        let mut string = FixedStr::new();
+        string.push_all(bytes);
+        string
+    }
+
+    /// Appends ASCII bytes, returning false if any byte is not ASCII or does not fit
+    pub(crate) const fn push_all(&mut self, bytes: &[u8]) -> bool {
+        let mut i = 0;
+        while i < bytes.len() {
+            if !self.push(bytes[i]) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.bytes.split_at(self.len).0
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Only ASCII bytes are ever pushed
+        core::str::from_utf8(self.as_bytes()).unwrap_or_default()
+    }
+}
+
+impl<const N: usize> Deref for FixedStr<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> PartialEq<str> for FixedStr<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for FixedStr<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
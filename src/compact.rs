@@ -0,0 +1,434 @@
+//! Fixed-size representations of identifiers, for storing large numbers of them
+//!
+//! Each compact type is `Copy`, hashes and compares as a single integer, and
+//! converts losslessly to and from the normalized form of its identifier.
+//!
+//! ```
+//! use library_stdnums::{CompactISBN, ISBN};
+//! let compact: CompactISBN = "0-306-40615-2".parse().unwrap();
+//! assert_eq!(compact.to_string(), "9780306406157");
+//! assert_eq!(ISBN::from(compact), ISBN::new("978-0-306-40615-7"));
+//! assert_eq!(std::mem::size_of::<CompactISBN>(), 8);
+//! ```
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
+
+use crate::buffer::FixedStr;
+#[cfg(feature = "alloc")]
+use crate::error::ParseError;
+use crate::{label, validate, IdentifierKind};
+#[cfg(feature = "alloc")]
+use crate::{Identifier, ISBN, ISSN, LCCN};
+
+/// An ISBN13 packed into a `u64`: the first twelve digits, with the check digit implied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CompactISBN(u64);
+
+/// An ISSN packed into a `u32`: the first seven digits, with the check digit implied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CompactISSN(u32);
+
+/// An LCCN packed into a `u64`
+///
+/// From the most significant bits: three six-bit prefix letters (zero when absent),
+/// a flag set for four digit years, a fourteen-bit year and a twenty-bit serial number.
+/// LCCNs therefore sort by prefix, then year, then serial number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CompactLCCN(u64);
+
+impl CompactISBN {
+    /// Packs an ISBN10 or ISBN13 without allocating
+    ///
+    /// Returns None if the ISBN is invalid. A label is removed, but Unicode is
+    /// not folded to ASCII; parse the string or convert from an [`ISBN`](crate::ISBN)
+    /// for that.
+    ///
+    /// ```
+    /// use library_stdnums::CompactISBN;
+    /// assert!(CompactISBN::new("978-1-449-37332-0").is_some());
+    /// assert!(CompactISBN::new("ISBN-13: 978-1-449-37332-0").is_some());
+    /// assert!(CompactISBN::new("978-1-449-37332-2").is_none());
+    /// assert!(CompactISBN::new("９７８１４４９３７３３２０").is_none());
+    /// ```
+    pub fn new(isbn: &str) -> Option<CompactISBN> {
+        let isbn = label::strip(IdentifierKind::ISBN, isbn);
+        let isbn13 = validate::isbn_to_13(isbn.as_bytes())?;
+        Some(CompactISBN(parse_digits(&isbn13.as_bytes()[..12])))
+    }
+
+    /// Unpacks a value returned by [`CompactISBN::get`]
+    ///
+    /// Returns None if the value has more than twelve digits
+    pub fn from_u64(value: u64) -> Option<CompactISBN> {
+        if value < 1_000_000_000_000 {
+            Some(CompactISBN(value))
+        } else {
+            None
+        }
+    }
+
+    /// The packed value: the first twelve digits of the ISBN13
+    pub fn get(self) -> u64 {
+        self.0
+    }
+
+    pub fn check_digit(self) -> char {
+        // Any placeholder works for the check digit position
+        let isbn13 = to_digits::<13>(self.0 * 10);
+        char::from(validate::isbn_check_digit(&isbn13).unwrap_or(b'0'))
+    }
+}
+
+impl CompactISSN {
+    /// Packs an ISSN without allocating
+    ///
+    /// Returns None if the ISSN is invalid. A label is removed, but Unicode is
+    /// not folded to ASCII; parse the string or convert from an [`ISSN`](crate::ISSN)
+    /// for that.
+    ///
+    /// ```
+    /// use library_stdnums::CompactISSN;
+    /// assert_eq!(CompactISSN::new("1043-383X").unwrap().to_string(), "1043383X");
+    /// assert_eq!(CompactISSN::new("eISSN 1043-383X").unwrap().to_string(), "1043383X");
+    /// assert!(CompactISSN::new("1043-3831").is_none());
+    /// ```
+    pub fn new(issn: &str) -> Option<CompactISSN> {
+        let issn = label::strip(IdentifierKind::ISSN, issn);
+        let normalized = validate::issn_normalize(issn.as_bytes())?;
+        Some(CompactISSN(parse_digits(&normalized.as_bytes()[..7]) as u32))
+    }
+
+    /// Unpacks a value returned by [`CompactISSN::get`]
+    ///
+    /// Returns None if the value has more than seven digits
+    pub fn from_u32(value: u32) -> Option<CompactISSN> {
+        if value < 10_000_000 {
+            Some(CompactISSN(value))
+        } else {
+            None
+        }
+    }
+
+    /// The packed value: the first seven digits of the ISSN
+    pub fn get(self) -> u32 {
+        self.0
+    }
+
+    pub fn check_digit(self) -> char {
+        char::from(validate::issn_weighted_check_digit(&to_digits::<7>(self.0 as u64)))
+    }
+}
+
+const LCCN_SERIAL_BITS: u32 = 20;
+const LCCN_YEAR_BITS: u32 = 14;
+const LCCN_PREFIX_LETTER_BITS: u32 = 6;
+const LCCN_FOUR_DIGIT_YEAR: u64 = 1 << (LCCN_SERIAL_BITS + LCCN_YEAR_BITS);
+const LCCN_PREFIX_SHIFT: u32 = LCCN_SERIAL_BITS + LCCN_YEAR_BITS + 1;
+
+impl CompactLCCN {
+    /// Packs an LCCN without allocating
+    ///
+    /// Returns None if the LCCN is invalid. A label is removed, but Unicode is
+    /// not folded to ASCII; parse the string or convert from an [`LCCN`](crate::LCCN)
+    /// for that.
+    ///
+    /// ```
+    /// use library_stdnums::CompactLCCN;
+    /// assert_eq!(CompactLCCN::new("n78-890351").unwrap().to_string(), "n78890351");
+    /// assert_eq!(CompactLCCN::new("LCCN n78-890351").unwrap().to_string(), "n78890351");
+    /// assert!(CompactLCCN::new("n78").is_none());
+    /// ```
+    pub fn new(lccn: &str) -> Option<CompactLCCN> {
+        let lccn = label::strip(IdentifierKind::LCCN, lccn);
+        let normalized = validate::lccn_normalize(lccn.as_bytes())?;
+        let normalized = normalized.as_bytes();
+        let (head, serial) = normalized.split_at(normalized.len() - 6);
+        let year_length = head.iter().rev().take_while(|byte| byte.is_ascii_digit()).count();
+        let (prefix, year) = head.split_at(head.len() - year_length);
+        let year_flag = match year.len() {
+            2 => 0,
+            4 => LCCN_FOUR_DIGIT_YEAR,
+            _ => return None,
+        };
+        let mut packed_prefix = 0;
+        for (index, &letter) in prefix.iter().enumerate() {
+            let shift = LCCN_PREFIX_LETTER_BITS * (2 - index as u32);
+            packed_prefix |= (encode_letter(letter)? as u64) << shift;
+        }
+        Some(CompactLCCN(
+            packed_prefix << LCCN_PREFIX_SHIFT
+                | year_flag
+                | parse_digits(year) << LCCN_SERIAL_BITS
+                | parse_digits(serial),
+        ))
+    }
+
+    /// Unpacks a value returned by [`CompactLCCN::get`]
+    ///
+    /// Returns None if the value does not describe an LCCN
+    pub fn from_u64(value: u64) -> Option<CompactLCCN> {
+        let compact = CompactLCCN(value);
+        let mut normalized: FixedStr<13> = FixedStr::new();
+        for part in [compact.prefix().as_bytes(), compact.year().as_bytes(), compact.serial().as_bytes()] {
+            for &byte in part {
+                normalized.push(byte);
+            }
+        }
+        CompactLCCN::new(&normalized).filter(|lccn| lccn.0 == value)
+    }
+
+    /// The packed value
+    pub fn get(self) -> u64 {
+        self.0
+    }
+
+    pub fn prefix(self) -> FixedStr<3> {
+        let packed_prefix = self.0 >> LCCN_PREFIX_SHIFT;
+        let mut prefix = FixedStr::new();
+        for index in (0..3).rev() {
+            let code = (packed_prefix >> (LCCN_PREFIX_LETTER_BITS * index)) & 0x3f;
+            if let Some(letter) = decode_letter(code as u8) {
+                prefix.push(letter);
+            }
+        }
+        prefix
+    }
+
+    pub fn year(self) -> FixedStr<4> {
+        let year = (self.0 >> LCCN_SERIAL_BITS) & ((1 << LCCN_YEAR_BITS) - 1);
+        if self.0 & LCCN_FOUR_DIGIT_YEAR == 0 {
+            FixedStr::from_bytes(&to_digits::<2>(year))
+        } else {
+            FixedStr::from_bytes(&to_digits::<4>(year))
+        }
+    }
+
+    pub fn serial(self) -> FixedStr<6> {
+        FixedStr::from_bytes(&to_digits::<6>(self.0 & ((1 << LCCN_SERIAL_BITS) - 1)))
+    }
+}
+
+// Lowercase letters are 1 to 26 and uppercase letters 27 to 52, leaving 0 for no letter
+fn encode_letter(letter: u8) -> Option<u8> {
+    match letter {
+        b'a'..=b'z' => Some(letter - b'a' + 1),
+        b'A'..=b'Z' => Some(letter - b'A' + 27),
+        _ => None,
+    }
+}
+
+fn decode_letter(code: u8) -> Option<u8> {
+    match code {
+        1..=26 => Some(b'a' + code - 1),
+        27..=52 => Some(b'A' + code - 27),
+        _ => None,
+    }
+}
+
+fn to_digits<const N: usize>(mut value: u64) -> [u8; N] {
+    let mut digits = [b'0'; N];
+    for digit in digits.iter_mut().rev() {
+        *digit = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+    digits
+}
+
+fn parse_digits(digits: &[u8]) -> u64 {
+    digits
+        .iter()
+        .fold(0, |value, digit| value * 10 + (digit - b'0') as u64)
+}
+
+impl fmt::Display for CompactISBN {
+    /// Displays the ISBN13
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:012}{}", self.0, self.check_digit())
+    }
+}
+
+impl fmt::Display for CompactISSN {
+    /// Displays the normalized ISSN
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:07}{}", self.0, self.check_digit())
+    }
+}
+
+impl fmt::Display for CompactLCCN {
+    /// Displays the normalized LCCN
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.prefix(), self.year(), self.serial())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for CompactISBN {
+    type Err = ParseError;
+
+    /// Cleans the string as [`ISBN`] does, so labels are removed and Unicode folded
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CompactISBN::try_from(&ISBN::new(s))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for CompactISSN {
+    type Err = ParseError;
+
+    /// Cleans the string as [`ISSN`] does, so labels are removed and Unicode folded
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CompactISSN::try_from(&ISSN::new(s))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for CompactLCCN {
+    type Err = ParseError;
+
+    /// Cleans the string as [`LCCN`] does, so labels are removed and Unicode folded
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CompactLCCN::try_from(&LCCN::new(s))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&ISBN> for CompactISBN {
+    type Error = ParseError;
+
+    /// Fails if the ISBN is invalid
+    fn try_from(isbn: &ISBN) -> Result<Self, Self::Error> {
+        // The canonical form has Unicode folded and any label removed
+        isbn.canonical()
+            .and_then(|canonical| CompactISBN::new(&canonical))
+            .ok_or_else(|| ParseError::InvalidISBN(isbn.identifier.clone()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&ISSN> for CompactISSN {
+    type Error = ParseError;
+
+    /// Fails if the ISSN is invalid
+    fn try_from(issn: &ISSN) -> Result<Self, Self::Error> {
+        issn.canonical()
+            .and_then(|canonical| CompactISSN::new(&canonical))
+            .ok_or_else(|| ParseError::InvalidISSN(issn.identifier.clone()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&LCCN> for CompactLCCN {
+    type Error = ParseError;
+
+    /// Fails if the LCCN is invalid, or if its prefix is not made of ASCII letters
+    fn try_from(lccn: &LCCN) -> Result<Self, Self::Error> {
+        lccn.canonical()
+            .and_then(|canonical| CompactLCCN::new(&canonical))
+            .ok_or_else(|| ParseError::InvalidLCCN(lccn.identifier.clone()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<CompactISBN> for ISBN {
+    fn from(compact: CompactISBN) -> Self {
+        ISBN::new(compact.to_string())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<CompactISSN> for ISSN {
+    fn from(compact: CompactISSN) -> Self {
+        ISSN::new(compact.to_string())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<CompactLCCN> for LCCN {
+    fn from(compact: CompactLCCN) -> Self {
+        LCCN::new(compact.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::{Normalize, Valid};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn it_round_trips_isbns() {
+        for isbn in ["0139381430", "0-8044-2957-X", "9781449373320", "9798531132178", "0000000000"] {
+            let compact = CompactISBN::try_from(&ISBN::new(isbn)).unwrap();
+            assert_eq!(compact.to_string(), ISBN::new(isbn).normalize().unwrap());
+            assert_eq!(ISBN::from(compact), ISBN::new(isbn));
+            assert_eq!(CompactISBN::from_u64(compact.get()), Some(compact));
+        }
+        assert_eq!(CompactISBN::new("0-8044-2957-X").unwrap().check_digit(), '3');
+        assert!(CompactISBN::from_u64(1_000_000_000_000).is_none());
+        assert_eq!(CompactISBN::try_from(&ISBN::new("Bad ISBN")), Err(ParseError::InvalidISBN("Bad ISBN".to_string())));
+    }
+
+    #[test]
+    fn it_round_trips_issns() {
+        for issn in ["0378-5955", "1043-383x", "0193-4511", "1561-4670"] {
+            let compact = CompactISSN::try_from(&ISSN::new(issn)).unwrap();
+            assert_eq!(compact.to_string(), ISSN::new(issn).normalize().unwrap());
+            assert_eq!(ISSN::from(compact), ISSN::new(issn));
+            assert_eq!(CompactISSN::from_u32(compact.get()), Some(compact));
+        }
+        assert_eq!(CompactISSN::new("1043-383x").unwrap().check_digit(), 'X');
+        assert_eq!(CompactISSN::new("0378-5955").unwrap().get(), 378595);
+        assert!(CompactISSN::from_u32(10_000_000).is_none());
+    }
+
+    #[test]
+    fn it_round_trips_lccns() {
+        for lccn in ["78-890351", "n78-890351", "2001-890351", "nb78-890351", "agr78-890351", "n2001-890351", "nb2001-890351", "N78-890351"] {
+            let compact = CompactLCCN::try_from(&LCCN::new(lccn)).unwrap();
+            assert_eq!(compact.to_string(), LCCN::new(lccn).normalize().unwrap());
+            assert_eq!(LCCN::from(compact), LCCN::new(lccn));
+            assert_eq!(CompactLCCN::from_u64(compact.get()), Some(compact));
+        }
+        let compact = CompactLCCN::new("agr78-890351").unwrap();
+        assert_eq!(compact.prefix(), "agr");
+        assert_eq!(compact.year(), "78");
+        assert_eq!(compact.serial(), "890351");
+        assert!(CompactLCCN::from_u64(u64::MAX).is_none());
+    }
+
+    #[test]
+    fn it_converts_labeled_and_unicode_identifiers() {
+        for isbn in ["ISBN-13: 978-0-306-40615-7", "９７８０３０６４０６１５７", "eISBN 0\u{2011}306\u{2011}40615\u{2011}2"] {
+            let isbn = ISBN::new(isbn);
+            assert!(isbn.valid());
+            assert_eq!(CompactISBN::try_from(&isbn).unwrap().to_string(), "9780306406157");
+            assert_eq!(isbn.identifier.parse::<CompactISBN>(), CompactISBN::try_from(&isbn));
+        }
+        for issn in ["e-ISSN: 1043-383X", "１０４３－３８３ｘ"] {
+            assert_eq!(CompactISSN::try_from(&ISSN::new(issn)).unwrap().to_string(), "1043383X");
+            assert_eq!(issn.parse::<CompactISSN>(), CompactISSN::try_from(&ISSN::new(issn)));
+        }
+        for lccn in ["LCCN n78-890351", "ｎ７８－８９０３５１"] {
+            assert_eq!(CompactLCCN::try_from(&LCCN::new(lccn)).unwrap().to_string(), "n78890351");
+            assert_eq!(lccn.parse::<CompactLCCN>(), CompactLCCN::try_from(&LCCN::new(lccn)));
+        }
+    }
+
+    #[test]
+    fn it_orders_compact_values() {
+        assert!(CompactISBN::new("0306406152").unwrap() < CompactISBN::new("9781449373320").unwrap());
+        assert!(CompactISSN::new("0193-4511").unwrap() < CompactISSN::new("0378-5955").unwrap());
+        let mut lccns: Vec<CompactLCCN> = ["nb78-890351", "2001-890351", "n78-890351", "78-890351", "n2001-890351"]
+            .iter()
+            .map(|lccn| lccn.parse().unwrap())
+            .collect();
+        lccns.sort();
+        let sorted: Vec<String> = lccns.iter().map(CompactLCCN::to_string).collect();
+        assert_eq!(sorted, ["78890351", "2001890351", "n78890351", "n2001890351", "nb78890351"]);
+    }
+}
@@ -0,0 +1,330 @@
+//! Normalization of an identifier column in CSV or TSV files
+//!
+//! Records are read, rewritten and written one at a time, so files of any size
+//! can be processed. The identifier column is replaced by its normalized form
+//! where the identifier is valid, and two columns are appended: a status
+//! (`valid`, `normalized`, `invalid` or `empty`) and the reason an identifier
+//! was rejected. Empty cells are left out of the [`Summary`].
+//!
+//! ```
+//! use library_stdnums::csv::ColumnNormalizer;
+//! use library_stdnums::IdentifierKind;
+//!
+//! let input = "title,isbn\nOne,0-306-40615-2\nTwo,bad\n";
+//! let mut output = Vec::new();
+//! let summary = ColumnNormalizer::new("isbn", IdentifierKind::ISBN)
+//!     .normalize(input.as_bytes(), &mut output)
+//!     .unwrap();
+//! assert_eq!(
+//!     String::from_utf8(output).unwrap(),
+//!     "title,isbn,status,error\nOne,9780306406157,normalized,\nTwo,bad,invalid,has no digits\n"
+//! );
+//! assert_eq!(summary.invalid, 1);
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::io::{Read, Write};
+
+use crate::batch::Summary;
+use crate::standard_number::StandardNumber;
+use crate::traits::{IdentifierKind, Normalize};
+use crate::{label, unicode, validate};
+
+/// The column holding identifiers, by header name or zero-based index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Column {
+    Name(String),
+    Index(usize),
+}
+
+impl From<&str> for Column {
+    fn from(name: &str) -> Self {
+        Column::Name(name.to_string())
+    }
+}
+
+impl From<String> for Column {
+    fn from(name: String) -> Self {
+        Column::Name(name)
+    }
+}
+
+impl From<usize> for Column {
+    fn from(index: usize) -> Self {
+        Column::Index(index)
+    }
+}
+
+/// The error returned when a file cannot be normalized
+#[derive(Debug)]
+pub enum CsvError {
+    /// The file could not be read or written
+    Csv(::csv::Error),
+    /// No header has the given name
+    MissingColumn(String),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Csv(error) => error.fmt(f),
+            CsvError::MissingColumn(name) => write!(f, "no column named {:?}", name),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Csv(error) => Some(error),
+            CsvError::MissingColumn(_) => None,
+        }
+    }
+}
+
+impl From<::csv::Error> for CsvError {
+    fn from(error: ::csv::Error) -> Self {
+        CsvError::Csv(error)
+    }
+}
+
+/// Rewrites one column of a CSV or TSV file to normalized identifiers
+#[derive(Debug, Clone)]
+pub struct ColumnNormalizer {
+    column: Column,
+    kind: IdentifierKind,
+    delimiter: u8,
+    has_headers: bool,
+}
+
+impl ColumnNormalizer {
+    /// Normalizes the given column of a comma-separated file with a header row
+    pub fn new(column: impl Into<Column>, kind: IdentifierKind) -> Self {
+        ColumnNormalizer {
+            column: column.into(),
+            kind,
+            delimiter: b',',
+            has_headers: true,
+        }
+    }
+
+    /// Reads and writes tab-separated values instead
+    pub fn tsv(self) -> Self {
+        self.delimiter(b'\t')
+    }
+
+    /// The byte separating fields, a comma by default
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Whether the first row holds column names, true by default
+    ///
+    /// Without headers, the column must be given by index.
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Streams records from the reader to the writer, normalizing the column
+    pub fn normalize<R: Read, W: Write>(&self, reader: R, writer: W) -> Result<Summary, CsvError> {
+        let mut reader = ::csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .has_headers(self.has_headers)
+            .from_reader(reader);
+        let mut writer = ::csv::WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .from_writer(writer);
+        let index = match &self.column {
+            Column::Index(index) => *index,
+            Column::Name(name) if self.has_headers => reader
+                .headers()?
+                .iter()
+                .position(|header| header == name)
+                .ok_or_else(|| CsvError::MissingColumn(name.clone()))?,
+            Column::Name(name) => return Err(CsvError::MissingColumn(name.clone())),
+        };
+        if self.has_headers {
+            let mut headers = reader.headers()?.clone();
+            headers.push_field("status");
+            headers.push_field("error");
+            writer.write_record(&headers)?;
+        }
+
+        let mut summary = Summary::default();
+        let mut record = ::csv::StringRecord::new();
+        let mut output = ::csv::StringRecord::new();
+        while reader.read_record(&mut record)? {
+            let cell = record.get(index).unwrap_or("").trim();
+            let outcome = Outcome::of(self.kind, cell);
+            if !cell.is_empty() {
+                summary.record(cell, outcome.normalized.as_deref());
+            }
+            output.clear();
+            for (position, field) in record.iter().enumerate() {
+                match &outcome.normalized {
+                    Some(normalized) if position == index => output.push_field(normalized),
+                    _ => output.push_field(field),
+                }
+            }
+            output.push_field(outcome.status);
+            output.push_field(&outcome.error);
+            writer.write_record(&output)?;
+        }
+        writer.flush().map_err(::csv::Error::from)?;
+        Ok(summary)
+    }
+}
+
+struct Outcome {
+    normalized: Option<String>,
+    status: &'static str,
+    error: String,
+}
+
+impl Outcome {
+    fn of(kind: IdentifierKind, cell: &str) -> Outcome {
+        if cell.is_empty() {
+            return Outcome { normalized: None, status: "empty", error: String::new() };
+        }
+        match StandardNumber::parse(kind, cell) {
+            Ok(number) => {
+                let normalized = number.normalize();
+                let status = if normalized.as_deref() == Some(cell) { "valid" } else { "normalized" };
+                Outcome { normalized, status, error: String::new() }
+            }
+            Err(_) => Outcome { normalized: None, status: "invalid", error: reason(kind, cell) },
+        }
+    }
+}
+
+/// Why a cell is not a valid identifier, read the way the parser reads it
+fn reason(kind: IdentifierKind, cell: &str) -> String {
+    let cleaned = unicode::clean(cell);
+    let stripped = label::strip(kind, &cleaned);
+    match kind {
+        IdentifierKind::ISBN => isbn_reason(stripped),
+        IdentifierKind::ISSN => issn_reason(stripped),
+        IdentifierKind::LCCN => lccn_reason(stripped),
+    }
+}
+
+// The parser reads the first run of digits, up to an X, and ignores the rest
+fn isbn_reason(isbn: &str) -> String {
+    let basic = match validate::isbn_basic(isbn.as_bytes()) {
+        Some(basic) => basic,
+        None => return "has more than 13 digits".to_string(),
+    };
+    if basic.is_empty() {
+        return "has no digits".to_string();
+    }
+    if basic.len() != 10 && basic.len() != 13 {
+        if basic.ends_with('X') {
+            return "has an X that is not the check digit".to_string();
+        }
+        return format!("has {} digits, not 10 or 13", basic.len());
+    }
+    match validate::isbn_check_digit(basic.as_bytes()) {
+        Some(check_digit) => format!("check digit should be {}", char::from(check_digit)),
+        None => "is not a valid ISBN".to_string(),
+    }
+}
+
+// The parser reads digits, X and hyphens only
+fn issn_reason(issn: &str) -> String {
+    let characters = issn.replace('-', "");
+    let digits = characters.trim_end_matches(['X', 'x']);
+    if !characters.chars().all(|c| c.is_ascii_digit() || c == 'X' || c == 'x') {
+        "contains characters other than digits, X and hyphens".to_string()
+    } else if characters.len() - digits.len() > 1 || digits.contains(['X', 'x']) {
+        "has an X that is not the check digit".to_string()
+    } else if characters.len() != 8 {
+        format!("has {} characters, not 8", characters.len())
+    } else {
+        match validate::issn_check_digit(characters.as_bytes()) {
+            Some(check_digit) => format!("check digit should be {}", char::from(check_digit)),
+            None => "is not a valid ISSN".to_string(),
+        }
+    }
+}
+
+// Whitespace and anything from the first slash are ignored
+fn lccn_reason(lccn: &str) -> String {
+    let before_suffix = lccn.split('/').next().unwrap_or("");
+    if !before_suffix.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c.is_whitespace()) {
+        "contains characters other than letters, digits and hyphens".to_string()
+    } else {
+        "is not a prefix, a two or four digit year and a six digit serial number".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(normalizer: ColumnNormalizer, input: &str) -> (String, Summary) {
+        let mut output = Vec::new();
+        let summary = normalizer.normalize(input.as_bytes(), &mut output).unwrap();
+        (String::from_utf8(output).unwrap(), summary)
+    }
+
+    #[test]
+    fn it_normalizes_a_tsv_column_by_index() {
+        let normalizer = ColumnNormalizer::new(1, IdentifierKind::ISSN).tsv().has_headers(false);
+        let (output, summary) = normalize(normalizer, "A\t0378-5955\nB\t03785955\nC\t\n");
+        assert_eq!(output, "A\t03785955\tnormalized\t\nB\t03785955\tvalid\t\nC\t\tempty\t\n");
+        assert_eq!(summary, Summary { valid: 2, invalid: 0, converted: 1 });
+    }
+
+    #[test]
+    fn it_reports_a_missing_column() {
+        let normalizer = ColumnNormalizer::new("lccn", IdentifierKind::LCCN);
+        let error = normalizer.normalize("isbn\n123\n".as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(error.to_string(), "no column named \"lccn\"");
+    }
+
+    #[test]
+    fn it_keeps_invalid_identifiers() {
+        let normalizer = ColumnNormalizer::new("lccn", IdentifierKind::LCCN);
+        let (output, _) = normalize(normalizer, "lccn,title\nn78-890351,One\n  zz1 ,Two\n");
+        assert_eq!(output, "lccn,title,status,error\nn78890351,One,normalized,\n  zz1 ,Two,invalid,\"is not a prefix, a two or four digit year and a six digit serial number\"\n");
+    }
+
+    #[test]
+    fn it_reports_why_an_identifier_is_invalid() {
+        let normalizer = ColumnNormalizer::new("id", IdentifierKind::ISBN);
+        let input = "id\n0-306-40615-3\n0306406153 (pbk.)\nABC0306406153\n0-306-4061\nISBN ０３０６Q06152\n03X6406152\n";
+        let (output, summary) = normalize(normalizer, input);
+        assert_eq!(
+            output,
+            "id,status,error\n\
+             0-306-40615-3,invalid,check digit should be 2\n\
+             0306406153 (pbk.),invalid,check digit should be 2\n\
+             ABC0306406153,invalid,check digit should be 2\n\
+             0-306-4061,invalid,\"has 8 digits, not 10 or 13\"\n\
+             ISBN ０３０６Q06152,invalid,\"has 4 digits, not 10 or 13\"\n\
+             03X6406152,invalid,has an X that is not the check digit\n"
+        );
+        assert_eq!(summary, Summary { valid: 0, invalid: 6, converted: 0 });
+
+        let normalizer = ColumnNormalizer::new("id", IdentifierKind::ISSN);
+        let (output, _) = normalize(normalizer, "id\nISSN 0378-5951\n0378-595\n0378-5955 (print)\n");
+        assert_eq!(
+            output,
+            "id,status,error\n\
+             ISSN 0378-5951,invalid,check digit should be 5\n\
+             0378-595,invalid,\"has 7 characters, not 8\"\n\
+             0378-5955 (print),invalid,\"contains characters other than digits, X and hyphens\"\n"
+        );
+
+        let normalizer = ColumnNormalizer::new("id", IdentifierKind::LCCN);
+        let (output, _) = normalize(normalizer, "id\nn78-89c0351!\nn78-89c0351\n");
+        assert_eq!(
+            output,
+            "id,status,error\n\
+             n78-89c0351!,invalid,\"contains characters other than letters, digits and hyphens\"\n\
+             n78-89c0351,invalid,\"is not a prefix, a two or four digit year and a six digit serial number\"\n"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use core::error::Error;
+use core::fmt;
+
+use alloc::string::{String, ToString};
+
+use crate::traits::IdentifierKind;
+
+/// The error returned when a string cannot be parsed into a standard number
+///
+/// Each variant carries the input that was rejected
+///
+/// ```
+/// use library_stdnums::{ISBN, ParseError};
+/// assert_eq!("Bad ISBN".parse::<ISBN>(), Err(ParseError::InvalidISBN("Bad ISBN".to_string())));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidISBN(String),
+    InvalidISSN(String),
+    InvalidLCCN(String),
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: IdentifierKind, input: &str) -> ParseError {
+        match kind {
+            IdentifierKind::ISBN => ParseError::InvalidISBN(input.to_string()),
+            IdentifierKind::ISSN => ParseError::InvalidISSN(input.to_string()),
+            IdentifierKind::LCCN => ParseError::InvalidLCCN(input.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidISBN(input) => write!(f, "invalid ISBN: {:?}", input),
+            ParseError::InvalidISSN(input) => write!(f, "invalid ISSN: {:?}", input),
+            ParseError::InvalidLCCN(input) => write!(f, "invalid LCCN: {:?}", input),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The reason an ISBN could not be converted to an ISBN10
+///
+/// Each variant carries the input that was rejected
+///
+/// ```
+/// use library_stdnums::{ConversionError, ISBN};
+/// assert_eq!(ISBN::new("9798531132178").convert_to_10(), Err(ConversionError::Prefix979("9798531132178".to_string())));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConversionError {
+    /// A valid ISBN13 beginning 979, which has no ISBN10
+    Prefix979(String),
+    /// An ISBN of the right length with the wrong check digit
+    Invalid(String),
+    /// Not 10 or 13 characters long, ignoring hyphens
+    WrongLength(String),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::Prefix979(input) => write!(f, "ISBN {:?} begins 979 and has no ISBN10", input),
+            ConversionError::Invalid(input) => write!(f, "invalid ISBN: {:?}", input),
+            ConversionError::WrongLength(input) => write!(f, "ISBN {:?} is not 10 or 13 digits long", input),
+        }
+    }
+}
+
+impl Error for ConversionError {}
+
+/// The rule an LCCN broke in strict validation
+///
+/// ```
+/// use library_stdnums::{LCCNRuleError, LCCN};
+/// assert_eq!(LCCN::new("zz78-890351").validate_strict_as_of(2024), Err(LCCNRuleError::UnknownPrefix("zz".to_string())));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LCCNRuleError {
+    /// The LCCN does not have a valid structure
+    Invalid(String),
+    /// The prefix is not one the Library of Congress has used
+    UnknownPrefix(String),
+    /// A four digit year before 2001, when they were introduced, or in the future
+    ImplausibleYear(String),
+}
+
+impl fmt::Display for LCCNRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LCCNRuleError::Invalid(input) => write!(f, "invalid LCCN: {:?}", input),
+            LCCNRuleError::UnknownPrefix(prefix) => write!(f, "unknown LCCN prefix: {:?}", prefix),
+            LCCNRuleError::ImplausibleYear(year) => write!(f, "implausible LCCN year: {:?}", year),
+        }
+    }
+}
+
+impl Error for LCCNRuleError {}
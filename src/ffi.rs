@@ -0,0 +1,174 @@
+//! C functions for validating, normalizing and converting identifiers
+//!
+//! Build a shared or static library with
+//!
+//! ```text
+//! cargo rustc --release --lib --features ffi --crate-type cdylib
+//! cargo rustc --release --lib --features ffi --crate-type staticlib
+//! ```
+//!
+//! and include `include/library_stdnums.h`, which `cbindgen` generates from this
+//! module with the settings in `cbindgen.toml`.
+//!
+//! Ownership rules:
+//!
+//! * Inputs are NUL-terminated UTF-8 strings that stay owned by the caller.
+//!   NULL and invalid UTF-8 are treated as invalid identifiers.
+//! * Every non-NULL `char *` returned is owned by the caller, and must be freed
+//!   with [`stdnum_string_free`], never with `free`.
+//! * NULL is returned when the identifier is invalid.
+//!
+//! ```c
+//! char *isbn13 = stdnum_isbn_convert_to_13("0-306-40615-2");
+//! if (isbn13) {
+//!     puts(isbn13);
+//!     stdnum_string_free(isbn13);
+//! }
+//! ```
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+use crate::{Normalize, Valid, ISBN, ISSN, LCCN};
+
+/// Whether the string is a valid ISBN-10 or ISBN-13
+///
+/// # Safety
+///
+/// `identifier` must be NULL or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn stdnum_isbn_valid(identifier: *const c_char) -> bool {
+    input(identifier).is_some_and(|identifier| ISBN::new(identifier).valid())
+}
+
+/// The ISBN-13 form of a valid ISBN, or NULL
+///
+/// # Safety
+///
+/// `identifier` must be NULL or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn stdnum_isbn_normalize(identifier: *const c_char) -> *mut c_char {
+    output(input(identifier).and_then(|identifier| ISBN::new(identifier).normalize()))
+}
+
+/// Converts a valid ISBN to ISBN-13, or returns NULL
+///
+/// # Safety
+///
+/// `identifier` must be NULL or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn stdnum_isbn_convert_to_13(identifier: *const c_char) -> *mut c_char {
+    output(input(identifier).and_then(|identifier| ISBN::new(identifier).convert_to_13()))
+}
+
+/// Converts a valid ISBN to ISBN-10, or returns NULL if it has no ISBN-10 form
+///
+/// # Safety
+///
+/// `identifier` must be NULL or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn stdnum_isbn_convert_to_10(identifier: *const c_char) -> *mut c_char {
+    output(input(identifier).and_then(|identifier| ISBN::new(identifier).convert_to_10().ok()))
+}
+
+/// Whether the string is a valid ISSN
+///
+/// # Safety
+///
+/// `identifier` must be NULL or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn stdnum_issn_valid(identifier: *const c_char) -> bool {
+    input(identifier).is_some_and(|identifier| ISSN::new(identifier).valid())
+}
+
+/// The eight character form of a valid ISSN, or NULL
+///
+/// # Safety
+///
+/// `identifier` must be NULL or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn stdnum_issn_normalize(identifier: *const c_char) -> *mut c_char {
+    output(input(identifier).and_then(|identifier| ISSN::new(identifier).normalize()))
+}
+
+/// Whether the string is a valid LCCN
+///
+/// # Safety
+///
+/// `identifier` must be NULL or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn stdnum_lccn_valid(identifier: *const c_char) -> bool {
+    input(identifier).is_some_and(|identifier| LCCN::new(identifier).valid())
+}
+
+/// The normalized form of a valid LCCN, or NULL
+///
+/// # Safety
+///
+/// `identifier` must be NULL or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn stdnum_lccn_normalize(identifier: *const c_char) -> *mut c_char {
+    output(input(identifier).and_then(|identifier| LCCN::new(identifier).normalize()))
+}
+
+/// Frees a string returned by this library. Passing NULL does nothing.
+///
+/// # Safety
+///
+/// `string` must be NULL or a pointer returned by this library that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn stdnum_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+unsafe fn input<'a>(identifier: *const c_char) -> Option<&'a str> {
+    if identifier.is_null() {
+        return None;
+    }
+    unsafe { CStr::from_ptr(identifier) }.to_str().ok()
+}
+
+fn output(value: Option<String>) -> *mut c_char {
+    value.and_then(|value| CString::new(value).ok()).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owned(string: *mut c_char) -> Option<String> {
+        if string.is_null() {
+            return None;
+        }
+        let value = unsafe { CStr::from_ptr(string) }.to_str().unwrap().to_string();
+        unsafe { stdnum_string_free(string) };
+        Some(value)
+    }
+
+    #[test]
+    fn it_validates_c_strings() {
+        unsafe {
+            assert!(stdnum_isbn_valid(c"0-306-40615-2".as_ptr()));
+            assert!(!stdnum_isbn_valid(c"0-306-40615-1".as_ptr()));
+            assert!(stdnum_issn_valid(c"1043-383x".as_ptr()));
+            assert!(stdnum_lccn_valid(c"n78-890351".as_ptr()));
+            assert!(!stdnum_lccn_valid(ptr::null()));
+            assert!(!stdnum_issn_valid(c"0378-5955\xff".as_ptr()), "Invalid UTF-8");
+        }
+    }
+
+    #[test]
+    fn it_returns_owned_strings() {
+        unsafe {
+            assert_eq!(owned(stdnum_isbn_normalize(c"0-306-40615-2".as_ptr())).unwrap(), "9780306406157");
+            assert_eq!(owned(stdnum_isbn_convert_to_13(c"0306406152".as_ptr())).unwrap(), "9780306406157");
+            assert_eq!(owned(stdnum_isbn_convert_to_10(c"9780306406157".as_ptr())).unwrap(), "0306406152");
+            assert_eq!(owned(stdnum_isbn_convert_to_10(c"9798531132178".as_ptr())), None);
+            assert_eq!(owned(stdnum_issn_normalize(c"1043-383x".as_ptr())).unwrap(), "1043383X");
+            assert_eq!(owned(stdnum_lccn_normalize(c"n78-890351".as_ptr())).unwrap(), "n78890351");
+            assert_eq!(owned(stdnum_lccn_normalize(ptr::null())), None);
+            stdnum_string_free(ptr::null_mut());
+        }
+    }
+}
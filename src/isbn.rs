@@ -1,5 +1,19 @@
-use crate::traits::{Normalize, Valid};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::error::{ConversionError, ParseError};
+use crate::isbn_ranges;
+use crate::label::{self, Label};
+use crate::traits::{Identifier, IdentifierKind, Normalize, Valid};
+use crate::{unicode, validate};
+
+#[derive(Debug, Clone)]
 pub struct ISBN {
   pub identifier: String,
 }
@@ -17,6 +31,17 @@ impl ISBN {
   /// assert_eq!(ISBN::new("0139381430").checkdigit().unwrap(), '0');
   /// ```
   ///
+  /// The checkdigit is calculated for the form the ISBN was given in, ISBN10 or
+  /// ISBN13, whether or not the given checkdigit is right. [`Identifier::check_digit`]
+  /// is the check digit of the ISBN13 of a valid ISBN
+  ///
+  /// ```
+  /// use library_stdnums::{Identifier, ISBN};
+  /// assert_eq!(ISBN::new("0-8044-2957-X").checkdigit(), Some('X'));
+  /// assert_eq!(ISBN::new("0-8044-2957-X").check_digit(), Some('3'));
+  /// assert_eq!(ISBN::new("0-8044-2957-1").checkdigit(), Some('X'));
+  /// ```
+  ///
   /// Returns None if the ISBN content is not valid length
   ///
   /// ```
@@ -24,12 +49,7 @@ impl ISBN {
   /// assert_eq!(ISBN::new("Bad ISBN").checkdigit(), None);
   /// ```
   pub fn checkdigit(&self) -> Option<char> {
-    let basic_string = self.reduce_to_basic();
-    match basic_string.len() {
-        10 => Some(checkdigit_ten(&self.identifier)),
-        13 => Some(checkdigit_thirteen(&self.identifier)),
-        _ => None
-    }
+    validate::isbn_check_digit(self.cleaned().as_bytes()).map(char::from)
   }
   /// Converts an ISBN to its corresponding ISBN13
   ///
@@ -54,151 +74,343 @@ impl ISBN {
   /// assert_eq!(ISBN::new("013938143").convert_to_13(), None);
   /// ```
   pub fn convert_to_13(&self) -> Option<String> {
-    if !&self.valid() {return None};
-    let basic_string = &self.reduce_to_basic();
-    let prepended_string = format!("{}{}", "978", &basic_string[..9]);
-    match basic_string.len() {
-      10 => Some(format!("{}{}", prepended_string, checkdigit_thirteen(&prepended_string)).to_string()),
-      13 => Some(basic_string.to_string()),
-      _ => None,
-    }
+    validate::isbn_to_13(self.cleaned().as_bytes()).map(|isbn13| isbn13.to_string())
   }
 
   /// Converts an ISBN to its corresponding ISBN10
   ///
-  /// Returns an Option<String> if the ISBN is valid
+  /// Returns the ISBN10 if the ISBN is valid
   /// 
   /// ```
   /// use library_stdnums::ISBN;
   /// assert_eq!(ISBN::new("9780306406157").convert_to_10().unwrap(), "0306406152");
   /// ```
   ///
-  /// Returns None if the ISBN is invalid
+  /// Returns an error saying why if the ISBN is invalid, or if an ISBN13 begins with '979'
   ///
   /// ```
-  /// use library_stdnums::ISBN;
-  /// assert_eq!(ISBN::new("013938143").convert_to_10(), None);
+  /// use library_stdnums::{ConversionError, ISBN};
+  /// assert_eq!(ISBN::new("013938143").convert_to_10(), Err(ConversionError::WrongLength("013938143".to_string())));
+  /// assert_eq!(ISBN::new("9780306406150").convert_to_10(), Err(ConversionError::Invalid("9780306406150".to_string())));
+  /// assert_eq!(ISBN::new("9798531132178").convert_to_10(), Err(ConversionError::Prefix979("9798531132178".to_string())));
   /// ```
-  /// 
-  /// Returns None if an ISBN13 begins with '979'
-  /// 
+  pub fn convert_to_10(&self) -> Result<String, ConversionError> {
+    let cleaned = self.cleaned();
+    if let Some(isbn10) = validate::isbn_to_10(cleaned.as_bytes()) {
+      return Ok(isbn10.to_string());
+    }
+    let error = match validate::isbn_basic(cleaned.as_bytes()).map(|basic| basic.len()) {
+      Some(10 | 13) if !self.valid() => ConversionError::Invalid,
+      Some(13) => ConversionError::Prefix979,
+      _ => ConversionError::WrongLength,
+    };
+    Err(error(self.identifier.clone()))
+  }
+
+  /// The best key for systems that only index ISBN10s
+  ///
+  /// Returns the ISBN10 where there is one, the ISBN13 of an ISBN beginning
+  /// '979', or None if the ISBN is invalid
+  ///
   /// ```
   /// use library_stdnums::ISBN;
-  /// assert_eq!(ISBN::new("9798531132178").convert_to_10(), None);
+  /// assert_eq!(ISBN::new("978-0-306-40615-7").legacy_key().unwrap(), "0306406152");
+  /// assert_eq!(ISBN::new("979-8-53-113217-8").legacy_key().unwrap(), "9798531132178");
+  /// assert_eq!(ISBN::new("Bad ISBN").legacy_key(), None);
   /// ```
-  pub fn convert_to_10(&self) -> Option<String> {
-    if !self.valid() {return None};
-    let basic_string = &self.reduce_to_basic();
-    if basic_string.starts_with("979") {
-      return None;
-    }
-    match basic_string.len() {
-      10 => Some(basic_string.to_string()),
-      13 => Some(format!("{}{}", &basic_string[3..12], checkdigit_ten(&basic_string[3..]))),
-      _ => None,
+  pub fn legacy_key(&self) -> Option<String> {
+    match self.convert_to_10() {
+      Ok(isbn10) => Some(isbn10),
+      Err(ConversionError::Prefix979(_)) => self.normalize(),
+      Err(_) => None,
     }
   }
 
-  fn reduce_to_basic(&self) -> String {
-    let clean_string = &self.identifier.replace("-", "");
-    scrub_alpha_prefix(clean_string)
+  /// Every form of the ISBN a search index should match
+  ///
+  /// The ISBN13 and ISBN10 unhyphenated, then hyphenated according to the
+  /// International ISBN Agency ranges. Forms that don't exist, such as the
+  /// ISBN10 of an ISBN beginning '979', are left out, as are the hyphenated
+  /// forms of an ISBN whose registration group is not in the ranges. Returns
+  /// an empty Vec if the ISBN is invalid
+  ///
+  /// ```
+  /// use library_stdnums::ISBN;
+  /// assert_eq!(
+  ///   ISBN::new("0-306-40615-2").search_variants(),
+  ///   ["9780306406157", "0306406152", "978-0-306-40615-7", "0-306-40615-2"]
+  /// );
+  /// ```
+  pub fn search_variants(&self) -> Vec<String> {
+    let mut variants = Vec::new();
+    let Some(isbn13) = self.convert_to_13() else {
+      return variants;
+    };
+    let parts = isbn_ranges::split(&isbn13);
+    if let Some(parts) = parts {
+      variants.push(parts.join("-"));
+    }
+    if let Ok(isbn10) = self.convert_to_10() {
+      if let Some([_, group, registrant, publication, _]) = parts {
+        variants.push(format!("{}-{}-{}-{}", group, registrant, publication, &isbn10[9..]));
+      }
+      variants.insert(0, isbn10);
+    }
+    variants.insert(0, isbn13);
+    variants
   }
-}
 
-impl Valid for ISBN {
-  /// Assert if the ISBN is valid by verifying the checkdigit
+  /// Maps an ISBN typed into a search box to the key its record is indexed under
+  ///
+  /// Any variant from `search_variants`, with or without spaces or a label,
+  /// gives the same ISBN13
   ///
-  /// Returns true if the ISBN is valid
-  /// 
   /// ```
   /// use library_stdnums::ISBN;
-  /// use library_stdnums::Valid;
-  /// assert!(ISBN::new("0139381430").valid());
+  /// assert_eq!(ISBN::search_key("ISBN 0 306 40615 2").unwrap(), "9780306406157");
+  /// assert_eq!(ISBN::search_key("978-0-306-40615-7").unwrap(), "9780306406157");
+  /// assert_eq!(ISBN::search_key("not an isbn"), None);
   /// ```
+  pub fn search_key(query: &str) -> Option<String> {
+    let query = unicode::clean(query);
+    let digits: String = query.split_whitespace().collect();
+    ISBN::new(digits).normalize()
+  }
+
+  /// The label in front of the ISBN, such as "ISBN-13:" or "eISBN"
   ///
-  /// Returns false if the ISBN is invalid
+  /// ```
+  /// use library_stdnums::label::Medium;
+  /// use library_stdnums::{Normalize, ISBN};
+  /// let isbn = ISBN::new("eISBN: 978-0-306-40615-7");
+  /// assert_eq!(isbn.label().unwrap().medium, Some(Medium::Electronic));
+  /// assert_eq!(isbn.normalize().unwrap(), "9780306406157");
+  /// assert_eq!(ISBN::new("9780306406157").label(), None);
+  /// ```
+  pub fn label(&self) -> Option<Label> {
+    label::parse(&unicode::clean(&self.identifier))
+      .map(|(label, _)| label)
+      .filter(|label| label.kind == IdentifierKind::ISBN)
+  }
+
+  pub(crate) fn reduce_to_basic(&self) -> String {
+    let clean_string = &self.cleaned().replace("-", "");
+    scrub_alpha_prefix(clean_string)
+  }
+
+  // The identifier with Unicode digits, dashes and spaces folded to ASCII, and any ISBN label removed
+  fn cleaned(&self) -> Cow<'_, str> {
+    label::strip_cleaned(IdentifierKind::ISBN, unicode::clean(&self.identifier))
+  }
+
+  // Valid ISBNs compare by their ISBN13, anything else by the raw identifier
+  fn comparison_key(&self) -> String {
+    self.normalize().unwrap_or_else(|| self.identifier.clone())
+  }
+}
+
+impl PartialEq for ISBN {
+  /// Two ISBNs are equal if they normalize to the same ISBN13
   ///
   /// ```
   /// use library_stdnums::ISBN;
-  /// use library_stdnums::Valid;
-  /// assert_eq!(ISBN::new("0139381432").valid(), false);
-  /// ```
-  fn valid(&self) -> bool {
-    let basic_string = self.reduce_to_basic();
-    match basic_string.len() {
-      10 => checkdigit_ten(&basic_string) == basic_string.chars().next_back().unwrap(),
-      13 => checkdigit_thirteen(&basic_string) == basic_string.chars().next_back().unwrap(),
-      _ => false
-    }
+  /// assert_eq!(ISBN::new("0-306-40615-2"), ISBN::new("9780306406157"));
+  /// assert_ne!(ISBN::new("0-306-40615-2"), ISBN::new("9781449373320"));
+  /// ```
+  fn eq(&self, other: &Self) -> bool {
+    self.comparison_key() == other.comparison_key()
+  }
+}
+
+impl Eq for ISBN {}
+
+impl Hash for ISBN {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.comparison_key().hash(state);
   }
 }
 
-impl Normalize for ISBN {
-  /// Converts an ISBN to a normalized ISBN13
+impl fmt::Display for ISBN {
+  /// Displays the identifier as it was given
   ///
-  /// Returns an Option<String> if the ISBN is valid
-  /// 
   /// ```
   /// use library_stdnums::ISBN;
-  /// use library_stdnums::Normalize;
-  /// assert_eq!(ISBN::new("ISBN: 978-0-306-40615-7").normalize().unwrap(), "9780306406157");
-  /// assert_eq!(ISBN::new("0-306-40615-2").normalize().unwrap(), "9780306406157");
+  /// assert_eq!(ISBN::new("0-306-40615-2").to_string(), "0-306-40615-2");
   /// ```
-  ///
-  /// Returns None if the ISBN is invalid
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.identifier)
+  }
+}
+
+impl FromStr for ISBN {
+  type Err = ParseError;
+
+  /// Parses a valid ISBN
   ///
   /// ```
   /// use library_stdnums::ISBN;
-  /// use library_stdnums::Normalize;
-  /// assert_eq!(ISBN::new("013938143").normalize(), None);
+  /// assert!("0-306-40615-2".parse::<ISBN>().is_ok());
+  /// assert!("0-306-40615-X".parse::<ISBN>().is_err());
   /// ```
-  fn normalize(&self) -> Option<String> {
-    self.convert_to_13()
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let isbn = ISBN::new(s);
+    if isbn.valid() {
+      Ok(isbn)
+    } else {
+      Err(ParseError::InvalidISBN(s.to_string()))
+    }
   }
 }
 
-fn scrub_alpha_prefix(string_to_scrub: &str) -> String {
-  string_to_scrub.chars()
-    .skip_while(|c| !c.is_ascii_digit())
-    .take_while(|c| c.is_ascii_digit() || c == &'X')
-    .collect::<String>()
+impl TryFrom<&str> for ISBN {
+  type Error = ParseError;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    value.parse()
+  }
 }
 
-fn checkdigit_ten(isbn: &str) -> char {
-  let basic_string = ISBN::new(isbn).reduce_to_basic();
-  let first_nine = basic_string.chars().take(9);
-  let first_nine_digits = first_nine.filter_map(|x| x.to_digit(10));
-  let multiplied = first_nine_digits.enumerate().map(|(index, digit)| digit * (10 - index as u32));
+impl TryFrom<String> for ISBN {
+  type Error = ParseError;
+
+  fn try_from(value: String) -> Result<Self, Self::Error> {
+    if ISBN::new(value.as_str()).valid() {
+      Ok(ISBN::new(value))
+    } else {
+      Err(ParseError::InvalidISBN(value))
+    }
+  }
+}
 
-  let summed: u32 = multiplied.sum();
-  let modulus: u32 = summed % 11;
-  from_digit_to_checkdigit(modulus)
+/// The components of an ISBN13, as assigned by the International ISBN Agency
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ISBNParts {
+  pub prefix: String,
+  pub registration_group: String,
+  pub registrant: String,
+  pub publication: String,
+  pub check_digit: char,
 }
 
-fn checkdigit_thirteen(isbn: &str) -> char {
-  let basic_string = ISBN::new(isbn).reduce_to_basic();
-  let first_twelve = basic_string.chars().take(12);
-  let first_twelve_digits = first_twelve.filter_map(|x| x.to_digit(10));
-  let multiplied = first_twelve_digits.enumerate().map(|(index, digit)| digit * (1 + (index as u32 % 2) * 2 ));
+impl Identifier for ISBN {
+  type Error = ParseError;
+  type Parts = ISBNParts;
+  const KIND: IdentifierKind = IdentifierKind::ISBN;
 
-  let summed: u32 = multiplied.sum();
-  let modulus = summed % 10;
-  let finished = (10 - modulus) % 10;
-  char::from_digit(finished, 10).unwrap()
+  fn parse(input: &str) -> Result<Self, Self::Error> {
+    input.parse()
+  }
+
+  /// The ISBN13 of a valid ISBN, or None if the ISBN is invalid
+  ///
+  /// ```
+  /// use library_stdnums::{Identifier, ISBN};
+  /// assert_eq!(ISBN::new("ISBN: 978-0-306-40615-7").canonical().unwrap(), "9780306406157");
+  /// assert_eq!(ISBN::new("0-306-40615-2").canonical().unwrap(), "9780306406157");
+  /// assert_eq!(ISBN::new("013938143").canonical(), None);
+  /// ```
+  fn canonical(&self) -> Option<String> {
+    self.convert_to_13()
+  }
+
+  /// Hyphenates the ISBN13 according to the International ISBN Agency ranges
+  ///
+  /// ```
+  /// use library_stdnums::{Identifier, ISBN};
+  /// assert_eq!(ISBN::new("0306406152").display().unwrap(), "978-0-306-40615-7");
+  /// assert_eq!(ISBN::new("9780228812340").display().unwrap(), "978-0-2288-1234-0");
+  /// ```
+  ///
+  /// Returns the unhyphenated ISBN13 if the registration group is not known
+  ///
+  /// ```
+  /// use library_stdnums::{Identifier, ISBN};
+  /// assert_eq!(ISBN::new("9789990200003").display().unwrap(), "9789990200003");
+  /// ```
+  fn display(&self) -> Option<String> {
+    let canonical = self.canonical()?;
+    match isbn_ranges::split(&canonical) {
+      Some(parts) => Some(parts.join("-")),
+      None => Some(canonical),
+    }
+  }
+
+  /// The check digit of the ISBN13, or None if the ISBN is invalid
+  ///
+  /// An ISBN10 has a different check digit from its ISBN13. [`ISBN::checkdigit`]
+  /// calculates the check digit of the form the ISBN was given in
+  ///
+  /// ```
+  /// use library_stdnums::{Identifier, ISBN};
+  /// assert_eq!(ISBN::new("0-306-40615-2").check_digit(), Some('7'));
+  /// assert_eq!(ISBN::new("0-306-40615-2").checkdigit(), Some('2'));
+  /// assert_eq!(ISBN::new("0-306-40615-X").check_digit(), None);
+  /// ```
+  fn check_digit(&self) -> Option<char> {
+    self.canonical()?.chars().next_back()
+  }
+
+  /// Splits the ISBN13 according to the International ISBN Agency ranges
+  ///
+  /// Returns None if the ISBN is invalid or its registration group is not known
+  ///
+  /// ```
+  /// use library_stdnums::{Identifier, ISBN};
+  /// let parts = ISBN::new("978-1-4493-7332-0").parts().unwrap();
+  /// assert_eq!(parts.registration_group, "1");
+  /// assert_eq!(parts.registrant, "4493");
+  /// ```
+  fn parts(&self) -> Option<ISBNParts> {
+    let canonical = self.canonical()?;
+    let [prefix, registration_group, registrant, publication, check_digit] = isbn_ranges::split(&canonical)?;
+    Some(ISBNParts {
+      prefix: prefix.to_string(),
+      registration_group: registration_group.to_string(),
+      registrant: registrant.to_string(),
+      publication: publication.to_string(),
+      check_digit: check_digit.chars().next()?,
+    })
+  }
+
+  /// ```
+  /// use library_stdnums::{Identifier, ISBN};
+  /// assert_eq!(ISBN::new("0-306-40615-2").uri().unwrap(), "urn:isbn:9780306406157");
+  /// ```
+  fn uri(&self) -> Option<String> {
+    Some(format!("urn:isbn:{}", self.canonical()?))
+  }
+
+  /// Whether the ISBN is an ISBN10 or ISBN13 with the right check digit
+  ///
+  /// ```
+  /// use library_stdnums::{Identifier, ISBN};
+  /// assert!(ISBN::new("0139381430").is_valid());
+  /// assert!(!ISBN::new("0139381432").is_valid());
+  /// ```
+  fn is_valid(&self) -> bool {
+    validate::isbn_valid(self.cleaned().as_bytes())
+  }
 }
 
-fn from_digit_to_checkdigit(num: u32) -> char {
-  let orig_num = char::from_digit((11_u32 - num) % 11, 11).unwrap();
-  if orig_num == 'a' {
-      'X'
-  } else {
-      orig_num
+fn scrub_alpha_prefix(string_to_scrub: &str) -> String {
+  let mut scrubbed = string_to_scrub.chars()
+    .skip_while(|c| !c.is_ascii_digit())
+    .take_while(|c| c.is_ascii_digit() || c.eq_ignore_ascii_case(&'X'))
+    .map(|c| c.to_ascii_uppercase())
+    .collect::<String>();
+  // Only the check digit can be an X
+  if let Some(x) = scrubbed.find('X') {
+    scrubbed.truncate(x + 1);
   }
+  scrubbed
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::label::Medium;
+  #[cfg(feature = "std")]
+  use std::collections::HashSet;
 
   #[test]
   fn it_can_create_an_isbn() {
@@ -220,6 +432,7 @@ mod tests {
     assert!(ISBN::new("9781449373320").valid());
     assert!(ISBN::new("0-8044-2957-X").valid());
     assert!(ISBN::new("ABC0139381430").valid());
+    assert!(ISBN::new("0-8044-2957-x").valid(), "A lowercase check digit is upcased, as the gem does");
   }
 
   #[test]
@@ -227,12 +440,26 @@ mod tests {
     assert!(!ISBN::new("01393814300").valid());
     assert!(!ISBN::new("0139381432").valid());
     assert!(!ISBN::new("9781449373322").valid());
+    assert!(!ISBN::new("406X0XX2XX").valid(), "Only the check digit can be an X");
   }
 
   #[test]
   fn it_reduces_to_basic() {
     assert_eq!(ISBN::new("0-8044-2957-X").reduce_to_basic(), "080442957X");
     assert_eq!(ISBN::new("ABC0139381430").reduce_to_basic(), "0139381430");
+    assert_eq!(ISBN::new("ISBN-13: 978-0-306-40615-7").reduce_to_basic(), "9780306406157");
+  }
+
+  #[test]
+  fn it_strips_labels() {
+    assert_eq!(ISBN::new("ISBN-13: 978-0-306-40615-7").normalize().unwrap(), "9780306406157");
+    assert_eq!(ISBN::new("ISBN 13: 978-0-306-40615-7").normalize().unwrap(), "9780306406157");
+    assert_eq!(ISBN::new("ISBN10 0-306-40615-2").convert_to_10().unwrap(), "0306406152");
+    assert_eq!(ISBN::new("e-ISBN：９７８０３０６４０６１５７").checkdigit(), Some('7'));
+    let isbn = ISBN::new("ISBN 0306406152 (pbk.)");
+    assert!(isbn.valid());
+    assert_eq!(isbn.label(), Some(Label { kind: IdentifierKind::ISBN, medium: Some(Medium::Print) }));
+    assert_eq!(ISBN::new("ISSN 0306406152").label(), None);
   }
 
   #[test]
@@ -242,6 +469,8 @@ mod tests {
     assert_eq!(scrub_alpha_prefix("ABC0139381430"), "0139381430");
     assert_eq!(scrub_alpha_prefix("ABC080442957X"), "080442957X");
     assert_eq!(scrub_alpha_prefix("ABC080442957Y"), "080442957");
+    assert_eq!(scrub_alpha_prefix("080442957x"), "080442957X");
+    assert_eq!(scrub_alpha_prefix("123X4567"), "123X");
   }
 
   #[test]
@@ -254,9 +483,65 @@ mod tests {
   fn it_converts_isbn_13_to_10() {
     assert_eq!(ISBN::new("9780306406157").convert_to_10().unwrap(), "0306406152");
     assert_eq!(ISBN::new("0306406152").convert_to_10().unwrap(), "0306406152");
-    assert_eq!(ISBN::new("9798531132178").convert_to_10(), None);
-    assert_eq!(ISBN::new("1").convert_to_10(), None);
-    assert_eq!(ISBN::new("9780306406157978030640615797803064061579780306406157").convert_to_10(), None);
+    assert_eq!(ISBN::new("9798531132178").convert_to_10(), Err(ConversionError::Prefix979("9798531132178".to_string())));
+    assert_eq!(ISBN::new("1").convert_to_10(), Err(ConversionError::WrongLength("1".to_string())));
+    assert!(matches!(
+      ISBN::new("9780306406157978030640615797803064061579780306406157").convert_to_10(),
+      Err(ConversionError::WrongLength(_))
+    ));
+    assert_eq!(ISBN::new("0306406151").convert_to_10(), Err(ConversionError::Invalid("0306406151".to_string())));
+    assert_eq!(ISBN::new("9798531132171").convert_to_10(), Err(ConversionError::Invalid("9798531132171".to_string())));
+    assert_eq!(ISBN::new("9790000006").convert_to_10().unwrap(), "9790000006");
+    assert_eq!(ISBN::new("9789790000001").convert_to_10().unwrap(), "9790000006");
+  }
+
+  #[test]
+  fn it_distinguishes_checkdigit_from_check_digit() {
+    // checkdigit is for the form given, check_digit for the ISBN13 of a valid ISBN
+    let isbn = ISBN::new("0-8044-2957-X");
+    assert_eq!(isbn.checkdigit(), Some('X'));
+    assert_eq!(isbn.check_digit(), Some('3'));
+    let isbn = ISBN::new("9780804429573");
+    assert_eq!(isbn.checkdigit(), isbn.check_digit());
+    let isbn = ISBN::new("0-8044-2957-0");
+    assert_eq!(isbn.checkdigit(), Some('X'));
+    assert_eq!(isbn.check_digit(), None);
+  }
+
+  #[test]
+  fn it_finds_the_legacy_key() {
+    assert_eq!(ISBN::new("ISBN 0-306-40615-2").legacy_key().unwrap(), "0306406152");
+    assert_eq!(ISBN::new("9798531132178").legacy_key().unwrap(), "9798531132178");
+    assert_eq!(ISBN::new("9798531132171").legacy_key(), None);
+    assert_eq!(ISBN::new("97900-0000-6").legacy_key().unwrap(), "9790000006");
+  }
+
+  #[test]
+  fn it_lists_the_search_variants() {
+    let variants = ISBN::new("978-1-4493-7332-0").search_variants();
+    assert_eq!(variants, ["9781449373320", "1449373321", "978-1-4493-7332-0", "1-4493-7332-1"]);
+    assert!(variants.iter().all(|variant| ISBN::search_key(variant).unwrap() == "9781449373320"));
+    assert_eq!(ISBN::new("9798531132178").search_variants(), ["9798531132178", "979-8-5311-3217-8"]);
+    assert_eq!(
+      ISBN::new("88-04-12345-1").search_variants(),
+      ["9788804123453", "8804123451", "978-88-04-12345-3", "88-04-12345-1"]
+    );
+    assert_eq!(
+      ISBN::new("9780228812340").search_variants(),
+      ["9780228812340", "0228812348", "978-0-2288-1234-0", "0-2288-1234-8"]
+    );
+    assert_eq!(ISBN::new("9789990200003").search_variants(), ["9789990200003", "9990200009"]);
+    // An ISBN10 in the 979 registration group keeps its own form among the variants
+    assert!(ISBN::new("9790000006").search_variants().contains(&"9790000006".to_string()));
+    assert!(ISBN::new("Bad ISBN").search_variants().is_empty());
+  }
+
+  #[test]
+  fn it_finds_the_search_key() {
+    assert_eq!(ISBN::search_key("  1449373321 ").unwrap(), "9781449373320");
+    assert_eq!(ISBN::search_key("1 4493 7332 1").unwrap(), "9781449373320");
+    assert_eq!(ISBN::search_key("１４４９３７３３２１").unwrap(), "9781449373320");
+    assert_eq!(ISBN::search_key("1449373322"), None);
   }
 
   #[test]
@@ -266,4 +551,50 @@ mod tests {
     assert_eq!(ISBN::new("ISBN: 978-0-306-40615-7").normalize().unwrap(), "9780306406157");
     assert_eq!(ISBN::new("ISBN: 978-0-306-40615-3").normalize(), None);
   }
+
+  #[test]
+  fn it_compares_on_the_normalized_form() {
+    assert_eq!(ISBN::new("0-306-40615-2"), ISBN::new("9780306406157"));
+    assert_eq!(ISBN::new("ISBN: 978-0-306-40615-7"), ISBN::new("0306406152"));
+    assert_ne!(ISBN::new("0-306-40615-2"), ISBN::new("0-306-40615-X"));
+    assert_eq!(ISBN::new("Bad ISBN"), ISBN::new("Bad ISBN"));
+  }
+
+  #[test]
+  #[cfg(feature = "std")]
+  fn it_hashes_on_the_normalized_form() {
+    let mut isbns = HashSet::new();
+    isbns.insert(ISBN::new("0-306-40615-2"));
+    isbns.insert(ISBN::new("9780306406157"));
+    isbns.insert(ISBN::new("978-1-449-37332-0"));
+    assert_eq!(isbns.len(), 2);
+    assert!(isbns.contains(&ISBN::new("978-0-306-40615-7")));
+  }
+
+  #[test]
+  fn it_parses() {
+    assert_eq!("0-306-40615-2".parse::<ISBN>().unwrap().identifier, "0-306-40615-2");
+    assert_eq!("0-306-40615-X".parse::<ISBN>(), Err(ParseError::InvalidISBN("0-306-40615-X".to_string())));
+    assert_eq!(ISBN::try_from("9780306406157").unwrap(), ISBN::new("0306406152"));
+    assert_eq!(ISBN::try_from(String::from("013938143")), Err(ParseError::InvalidISBN("013938143".to_string())));
+  }
+
+  #[test]
+  fn it_implements_identifier() {
+    let isbn = ISBN::parse("0-8044-2957-X").unwrap();
+    assert_eq!(isbn.kind(), IdentifierKind::ISBN);
+    assert_eq!(isbn.canonical().unwrap(), "9780804429573");
+    assert_eq!(isbn.display().unwrap(), "978-0-8044-2957-3");
+    assert_eq!(isbn.check_digit(), Some('3'));
+    assert_eq!(isbn.uri().unwrap(), "urn:isbn:9780804429573");
+    assert_eq!(isbn.parts().unwrap(), ISBNParts {
+      prefix: "978".to_string(),
+      registration_group: "0".to_string(),
+      registrant: "8044".to_string(),
+      publication: "2957".to_string(),
+      check_digit: '3',
+    });
+    assert!(ISBN::parse("0-8044-2957-1").is_err());
+    assert_eq!(ISBN::new("0-8044-2957-1").display(), None);
+  }
 }
@@ -0,0 +1,119 @@
+//! ISBN hyphenation ranges
+//!
+//! `table.rs` is generated by `generate.py` from the International ISBN
+//! Agency's [RangeMessage.xml](https://www.isbn-international.org/range_file_generation),
+//! which is checked in next to it. The checked-in copy was transcribed without
+//! access to the agency's site, so replace it with a fresh download and rerun
+//! the generator before relying on the ranges of smaller registration groups.
+//! ISBNs from registration groups missing from the message cannot be hyphenated.
+//!
+//! Ranges are expressed, as in RangeMessage.xml, over the first seven digits
+//! following the prefix, and map to the length of the next element.
+mod table;
+
+use table::{EAN_978, EAN_979, GROUPS};
+
+struct Range {
+    start: u32,
+    end: u32,
+    length: usize,
+}
+
+const fn range(start: u32, end: u32, length: usize) -> Range {
+    Range { start, end, length }
+}
+
+struct Group {
+    prefix: &'static str,
+    registrants: &'static [Range],
+}
+
+/// Split a valid ISBN13 into its prefix, registration group, registrant,
+/// publication and check digit
+pub(crate) fn split(isbn13: &str) -> Option<[&str; 5]> {
+    let prefix = isbn13.get(..3)?;
+    let body = isbn13.get(3..12)?;
+    let check_digit = isbn13.get(12..13)?;
+    let prefix_ranges = match prefix {
+        "978" => EAN_978,
+        "979" => EAN_979,
+        _ => return None,
+    };
+    let group_length = length_in(prefix_ranges, body)?;
+    let (group, rest) = body.split_at(group_length);
+    let registrants = GROUPS
+        .iter()
+        .find(|candidate| candidate.prefix.split_once('-') == Some((prefix, group)))?
+        .registrants;
+    let registrant_length = length_in(registrants, rest)?;
+    if registrant_length >= rest.len() {
+        return None;
+    }
+    let (registrant, publication) = rest.split_at(registrant_length);
+    Some([prefix, group, registrant, publication, check_digit])
+}
+
+fn length_in(ranges: &[Range], digits: &str) -> Option<usize> {
+    // The first seven digits, padded with zeros on the right
+    let mut value = 0;
+    for index in 0..7 {
+        let digit = digits.as_bytes().get(index).map_or(0, |byte| byte.wrapping_sub(b'0'));
+        if digit > 9 {
+            return None;
+        }
+        value = value * 10 + digit as u32;
+    }
+    ranges
+        .iter()
+        .find(|range| range.start <= value && value <= range.end)
+        .map(|range| range.length)
+        .filter(|&length| length > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_splits_isbns_into_their_parts() {
+        assert_eq!(split("9780306406157"), Some(["978", "0", "306", "40615", "7"]));
+        assert_eq!(split("9781449373320"), Some(["978", "1", "4493", "7332", "0"]));
+        assert_eq!(split("9780804429573"), Some(["978", "0", "8044", "2957", "3"]));
+        assert_eq!(split("9798531132178"), Some(["979", "8", "5311", "3217", "8"]));
+        assert_eq!(split("9791032305690"), Some(["979", "10", "323", "0569", "0"]));
+    }
+
+    #[test]
+    fn it_splits_every_registration_group() {
+        assert_eq!(split("9788420000001"), Some(["978", "84", "200", "0000", "1"]));
+        assert_eq!(split("9788804123456"), Some(["978", "88", "04", "12345", "6"]));
+        assert_eq!(split("9789211012340"), Some(["978", "92", "1", "101234", "0"]));
+        assert_eq!(split("9786071234567"), Some(["978", "607", "12", "3456", "7"]));
+        assert_eq!(split("9789992158104"), Some(["978", "99921", "58", "10", "4"]));
+        assert_eq!(split("9791112345678"), Some(["979", "11", "12", "34567", "8"]));
+    }
+
+    #[test]
+    fn it_finds_registrant_lengths_at_range_boundaries() {
+        // 978-0: 229-368 has three digit registrants, 2280-2289 four
+        assert_eq!(split("9780227999999"), Some(["978", "0", "227", "99999", "9"]));
+        assert_eq!(split("9780228000000"), Some(["978", "0", "2280", "0000", "0"]));
+        assert_eq!(split("9780228812340"), Some(["978", "0", "2288", "1234", "0"]));
+        assert_eq!(split("9780228999999"), Some(["978", "0", "2289", "9999", "9"]));
+        assert_eq!(split("9780229000000"), Some(["978", "0", "229", "00000", "0"]));
+        // 978-1: 7170-7399 has four digit registrants, 74000-77499 five
+        assert_eq!(split("9781732345676"), Some(["978", "1", "7323", "4567", "6"]));
+        assert_eq!(split("9781739999999"), Some(["978", "1", "7399", "9999", "9"]));
+        assert_eq!(split("9781740000000"), Some(["978", "1", "74000", "000", "0"]));
+        // Seven digit registrants leave a single digit publication
+        assert_eq!(split("9780639800001"), Some(["978", "0", "6398000", "0", "1"]));
+    }
+
+    #[test]
+    fn it_does_not_split_unknown_groups() {
+        assert_eq!(split("9786600000001"), None);
+        assert_eq!(split("9789990200000"), None);
+        assert_eq!(split("9790000000001"), None);
+        assert_eq!(split("1234567890123"), None);
+    }
+}
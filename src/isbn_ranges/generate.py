@@ -0,0 +1,73 @@
+#!/usr/bin/env python3
+"""Generates table.rs, the ISBN hyphenation ranges, from RangeMessage.xml.
+
+RangeMessage.xml is the International ISBN Agency's range message, from
+https://www.isbn-international.org/range_file_generation. To update the
+ranges, replace it with a new download and run
+
+    python3 src/isbn_ranges/generate.py > src/isbn_ranges/table.rs
+"""
+import os
+import sys
+import xml.etree.ElementTree as ElementTree
+
+HERE = os.path.dirname(os.path.abspath(__file__))
+
+
+def rules(element, prefix):
+    """The (start, end, length) rules of a prefix or group, checked for sense"""
+    parsed = []
+    for rule in element.findall("Rules/Rule"):
+        start, end = rule.findtext("Range").split("-")
+        length = int(rule.findtext("Length"))
+        if len(start) != 7 or len(end) != 7 or int(start) > int(end):
+            sys.exit(f"{prefix}: bad range {start}-{end}")
+        parsed.append((int(start), int(end), length))
+    for (_, end, _), (start, _, _) in zip(parsed, parsed[1:]):
+        if start <= end:
+            sys.exit(f"{prefix}: ranges overlap or are out of order at {start:07}")
+    # The group and registrant leave at least one digit for the publication
+    digits = 9 - sum(len(part) for part in prefix.split("-")[1:])
+    for start, _, length in parsed:
+        if length >= digits:
+            sys.exit(f"{prefix}: length {length} at {start:07} leaves no publication digits")
+    return parsed
+
+
+def ranges(parsed, indent):
+    lines = [f"{indent}range({start}, {end}, {length})," for start, end, length in parsed]
+    return "\n".join(lines)
+
+
+def main():
+    root = ElementTree.parse(os.path.join(HERE, "RangeMessage.xml")).getroot()
+    date = root.findtext("MessageDate")
+    print("// Generated by generate.py from RangeMessage.xml. Do not edit.")
+    if date:
+        print(f"// Message date: {date}")
+    print("use super::{range, Group, Range};")
+    for prefix in root.findall("EAN.UCCPrefixes/EAN.UCC"):
+        name = prefix.findtext("Prefix")
+        print()
+        print(f"pub(super) const EAN_{name}: &[Range] = &[")
+        print(ranges(rules(prefix, name), "    "))
+        print("];")
+    print()
+    print("pub(super) const GROUPS: &[Group] = &[")
+    for group in root.findall("RegistrationGroups/Group"):
+        name = group.findtext("Prefix")
+        registrants = rules(group, name)
+        print("    Group {")
+        print(f"        prefix: {name!r},".replace("'", '"'))
+        if registrants:
+            print("        registrants: &[")
+            print(ranges(registrants, "            "))
+            print("        ],")
+        else:
+            print("        registrants: &[],")
+        print("    },")
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
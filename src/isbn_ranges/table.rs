@@ -0,0 +1,2734 @@
+// Generated by generate.py from RangeMessage.xml. Do not edit.
+use super::{range, Group, Range};
+
+pub(super) const EAN_978: &[Range] = &[
+    range(0, 5999999, 1),
+    range(6000000, 6499999, 3),
+    range(6500000, 6599999, 2),
+    range(6600000, 6999999, 0),
+    range(7000000, 7999999, 1),
+    range(8000000, 9499999, 2),
+    range(9500000, 9899999, 3),
+    range(9900000, 9989999, 4),
+    range(9990000, 9999999, 5),
+];
+
+pub(super) const EAN_979: &[Range] = &[
+    range(0, 999999, 0),
+    range(1000000, 1299999, 2),
+    range(1300000, 7999999, 0),
+    range(8000000, 8999999, 1),
+    range(9000000, 9999999, 0),
+];
+
+pub(super) const GROUPS: &[Group] = &[
+    Group {
+        prefix: "978-0",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 2279999, 3),
+            range(2280000, 2289999, 4),
+            range(2290000, 3689999, 3),
+            range(3690000, 3699999, 4),
+            range(3700000, 6389999, 3),
+            range(6390000, 6397999, 4),
+            range(6398000, 6399999, 7),
+            range(6400000, 6449999, 3),
+            range(6450000, 6459999, 7),
+            range(6460000, 6479999, 3),
+            range(6480000, 6489999, 7),
+            range(6490000, 6999999, 3),
+            range(7000000, 8499999, 4),
+            range(8500000, 8999999, 5),
+            range(9000000, 9499999, 6),
+            range(9500000, 9999999, 7),
+        ],
+    },
+    Group {
+        prefix: "978-1",
+        registrants: &[
+            range(0, 99999, 2),
+            range(100000, 299999, 3),
+            range(300000, 349999, 4),
+            range(350000, 399999, 3),
+            range(400000, 499999, 4),
+            range(500000, 699999, 3),
+            range(700000, 999999, 4),
+            range(1000000, 3979999, 3),
+            range(3980000, 5499999, 4),
+            range(5500000, 6498999, 5),
+            range(6499000, 6499999, 7),
+            range(6500000, 6799999, 4),
+            range(6800000, 6859999, 5),
+            range(6860000, 7139999, 4),
+            range(7140000, 7169999, 3),
+            range(7170000, 7399999, 4),
+            range(7400000, 7749999, 5),
+            range(7750000, 7753999, 7),
+            range(7754000, 7763999, 5),
+            range(7764000, 7764999, 7),
+            range(7765000, 7769999, 5),
+            range(7770000, 7782999, 7),
+            range(7783000, 7899999, 5),
+            range(7900000, 7999999, 4),
+            range(8000000, 8671999, 5),
+            range(8672000, 8675999, 4),
+            range(8676000, 8697999, 5),
+            range(8698000, 9159999, 6),
+            range(9160000, 9165059, 7),
+            range(9165060, 9168699, 6),
+            range(9168700, 9169079, 7),
+            range(9169080, 9195999, 6),
+            range(9196000, 9196549, 7),
+            range(9196550, 9729999, 6),
+            range(9730000, 9877999, 4),
+            range(9878000, 9911499, 6),
+            range(9911500, 9911999, 7),
+            range(9912000, 9989899, 6),
+            range(9989900, 9999999, 7),
+        ],
+    },
+    Group {
+        prefix: "978-2",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 3499999, 3),
+            range(3500000, 3999999, 5),
+            range(4000000, 4869999, 3),
+            range(4870000, 4949999, 6),
+            range(4950000, 4959999, 3),
+            range(4960000, 4966999, 4),
+            range(4967000, 4969999, 5),
+            range(4970000, 5279999, 3),
+            range(5280000, 5299999, 4),
+            range(5300000, 6999999, 3),
+            range(7000000, 8399999, 4),
+            range(8400000, 8999999, 5),
+            range(9000000, 9197999, 6),
+            range(9198000, 9198099, 5),
+            range(9198100, 9199429, 6),
+            range(9199430, 9199689, 7),
+            range(9199690, 9499999, 6),
+            range(9500000, 9999999, 7),
+        ],
+    },
+    Group {
+        prefix: "978-3",
+        registrants: &[
+            range(0, 299999, 2),
+            range(300000, 339999, 3),
+            range(340000, 369999, 4),
+            range(370000, 399999, 5),
+            range(400000, 1999999, 2),
+            range(2000000, 6999999, 3),
+            range(7000000, 8499999, 4),
+            range(8500000, 8999999, 5),
+            range(9000000, 9499999, 6),
+            range(9500000, 9539999, 7),
+            range(9540000, 9699999, 5),
+            range(9700000, 9849999, 7),
+            range(9850000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-4",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 6999999, 3),
+            range(7000000, 8499999, 4),
+            range(8500000, 8999999, 5),
+            range(9000000, 9499999, 6),
+            range(9500000, 9999999, 7),
+        ],
+    },
+    Group {
+        prefix: "978-5",
+        registrants: &[
+            range(0, 49999, 5),
+            range(50000, 99999, 4),
+            range(100000, 1999999, 2),
+            range(2000000, 3619999, 3),
+            range(3620000, 3623999, 4),
+            range(3624000, 3629999, 7),
+            range(3630000, 4209999, 3),
+            range(4210000, 4299999, 4),
+            range(4300000, 4309999, 3),
+            range(4310000, 4399999, 4),
+            range(4400000, 4409999, 3),
+            range(4410000, 4499999, 4),
+            range(4500000, 6039999, 3),
+            range(6040000, 6049999, 7),
+            range(6050000, 6999999, 3),
+            range(7000000, 8499999, 4),
+            range(8500000, 8999999, 5),
+            range(9000000, 9099999, 6),
+            range(9100000, 9199999, 5),
+            range(9200000, 9299999, 4),
+            range(9300000, 9499999, 5),
+            range(9500000, 9500999, 7),
+            range(9501000, 9799999, 4),
+            range(9800000, 9899999, 5),
+            range(9900000, 9909999, 7),
+            range(9910000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-600",
+        registrants: &[
+            range(0, 999999, 2),
+            range(1000000, 4999999, 3),
+            range(5000000, 8999999, 4),
+            range(9000000, 9867999, 5),
+            range(9868000, 9929999, 4),
+            range(9930000, 9959999, 3),
+            range(9960000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-601",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 6999999, 3),
+            range(7000000, 7999999, 4),
+            range(8000000, 8499999, 5),
+            range(8500000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-602",
+        registrants: &[
+            range(0, 699999, 2),
+            range(700000, 1399999, 4),
+            range(1400000, 1499999, 5),
+            range(1500000, 1699999, 4),
+            range(1700000, 1999999, 5),
+            range(2000000, 4999999, 3),
+            range(5000000, 5399999, 5),
+            range(5400000, 5999999, 4),
+            range(6000000, 6199999, 5),
+            range(6200000, 6999999, 4),
+            range(7000000, 7499999, 5),
+            range(7500000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-603",
+        registrants: &[
+            range(0, 499999, 2),
+            range(500000, 4999999, 2),
+            range(5000000, 7999999, 3),
+            range(8000000, 8999999, 4),
+            range(9000000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-604",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 3999999, 3),
+            range(4000000, 4699999, 2),
+            range(4700000, 4979999, 3),
+            range(4980000, 4999999, 4),
+            range(5000000, 8999999, 2),
+            range(9000000, 9799999, 3),
+            range(9800000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-605",
+        registrants: &[
+            range(0, 299999, 2),
+            range(300000, 399999, 3),
+            range(400000, 599999, 2),
+            range(600000, 699999, 5),
+            range(700000, 999999, 2),
+            range(1000000, 1999999, 3),
+            range(2000000, 2399999, 4),
+            range(2400000, 3999999, 3),
+            range(4000000, 5999999, 4),
+            range(6000000, 7499999, 5),
+            range(7500000, 7999999, 4),
+            range(8000000, 8999999, 5),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-606",
+        registrants: &[
+            range(0, 999999, 3),
+            range(1000000, 4999999, 2),
+            range(5000000, 7999999, 3),
+            range(8000000, 9099999, 4),
+            range(9100000, 9199999, 3),
+            range(9200000, 9599999, 5),
+            range(9600000, 9749999, 4),
+            range(9750000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-607",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 5929999, 3),
+            range(5930000, 5999999, 5),
+            range(6000000, 7499999, 3),
+            range(7500000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-608",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 1999999, 2),
+            range(2000000, 4499999, 3),
+            range(4500000, 6499999, 4),
+            range(6500000, 6999999, 5),
+            range(7000000, 9999999, 1),
+        ],
+    },
+    Group {
+        prefix: "978-609",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 7999999, 3),
+            range(8000000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-611",
+        registrants: &[],
+    },
+    Group {
+        prefix: "978-612",
+        registrants: &[
+            range(0, 2999999, 2),
+            range(3000000, 3999999, 3),
+            range(4000000, 4499999, 4),
+            range(4500000, 4999999, 5),
+            range(5000000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-613",
+        registrants: &[
+            range(0, 9999999, 1),
+        ],
+    },
+    Group {
+        prefix: "978-614",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 7999999, 3),
+            range(8000000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-615",
+        registrants: &[
+            range(0, 999999, 2),
+            range(1000000, 4999999, 3),
+            range(5000000, 7999999, 4),
+            range(8000000, 8999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-616",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 6999999, 3),
+            range(7000000, 8999999, 4),
+            range(9000000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-617",
+        registrants: &[
+            range(0, 4999999, 2),
+            range(5000000, 6999999, 3),
+            range(7000000, 8999999, 4),
+            range(9000000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-618",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 4999999, 3),
+            range(5000000, 7999999, 4),
+            range(8000000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-619",
+        registrants: &[
+            range(0, 1499999, 2),
+            range(1500000, 6999999, 3),
+            range(7000000, 8999999, 4),
+            range(9000000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-620",
+        registrants: &[
+            range(0, 9999999, 1),
+        ],
+    },
+    Group {
+        prefix: "978-621",
+        registrants: &[
+            range(0, 2999999, 2),
+            range(3000000, 3999999, 0),
+            range(4000000, 5999999, 3),
+            range(6000000, 7999999, 0),
+            range(8000000, 8999999, 4),
+            range(9000000, 9499999, 0),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-622",
+        registrants: &[
+            range(0, 1099999, 2),
+            range(1100000, 1999999, 0),
+            range(2000000, 4599999, 3),
+            range(4600000, 8749999, 4),
+            range(8750000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-623",
+        registrants: &[
+            range(0, 1099999, 2),
+            range(1100000, 5249999, 3),
+            range(5250000, 8799999, 4),
+            range(8800000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-624",
+        registrants: &[
+            range(0, 499999, 2),
+            range(500000, 1999999, 0),
+            range(2000000, 2499999, 3),
+            range(2500000, 4999999, 0),
+            range(5000000, 6699999, 4),
+            range(6700000, 9299999, 0),
+            range(9300000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-625",
+        registrants: &[
+            range(0, 199999, 2),
+            range(200000, 3649999, 0),
+            range(3650000, 4429999, 3),
+            range(4430000, 4449999, 5),
+            range(4450000, 4499999, 3),
+            range(4500000, 5999999, 0),
+            range(6000000, 7793999, 4),
+            range(7794000, 7794999, 5),
+            range(7795000, 8499999, 4),
+            range(8500000, 9399999, 0),
+            range(9400000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-626",
+        registrants: &[
+            range(0, 499999, 2),
+            range(500000, 2999999, 0),
+            range(3000000, 4999999, 3),
+            range(5000000, 6999999, 0),
+            range(7000000, 7999999, 4),
+            range(8000000, 9499999, 0),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-627",
+        registrants: &[
+            range(0, 2999999, 0),
+            range(3000000, 3199999, 2),
+            range(3200000, 4999999, 0),
+            range(5000000, 5249999, 3),
+            range(5250000, 7499999, 0),
+            range(7500000, 7999999, 4),
+            range(8000000, 9449999, 0),
+            range(9450000, 9464999, 5),
+            range(9465000, 9999999, 0),
+        ],
+    },
+    Group {
+        prefix: "978-628",
+        registrants: &[
+            range(0, 999999, 2),
+            range(1000000, 4999999, 0),
+            range(5000000, 5499999, 3),
+            range(5500000, 7499999, 0),
+            range(7500000, 8499999, 4),
+            range(8500000, 9499999, 0),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-629",
+        registrants: &[
+            range(0, 299999, 2),
+            range(300000, 4599999, 0),
+            range(4600000, 4999999, 3),
+            range(5000000, 7499999, 0),
+            range(7500000, 7999999, 4),
+            range(8000000, 9499999, 0),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-630",
+        registrants: &[
+            range(0, 2999999, 0),
+            range(3000000, 3499999, 3),
+            range(3500000, 6499999, 0),
+            range(6500000, 6849999, 4),
+            range(6850000, 9999999, 0),
+        ],
+    },
+    Group {
+        prefix: "978-631",
+        registrants: &[
+            range(0, 999999, 2),
+            range(1000000, 2999999, 0),
+            range(3000000, 3999999, 3),
+            range(4000000, 6499999, 0),
+            range(6500000, 7499999, 4),
+            range(7500000, 8999999, 0),
+            range(9000000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-65",
+        registrants: &[
+            range(0, 199999, 2),
+            range(200000, 2499999, 0),
+            range(2500000, 2999999, 3),
+            range(3000000, 3029999, 3),
+            range(3030000, 4999999, 0),
+            range(5000000, 5129999, 4),
+            range(5130000, 5349999, 0),
+            range(5350000, 6149999, 4),
+            range(6150000, 7999999, 0),
+            range(8000000, 8182499, 5),
+            range(8182500, 8299999, 0),
+            range(8300000, 8999999, 5),
+            range(9000000, 9024499, 6),
+            range(9024500, 9799999, 0),
+            range(9800000, 9999999, 6),
+        ],
+    },
+    Group {
+        prefix: "978-7",
+        registrants: &[
+            range(0, 999999, 2),
+            range(1000000, 4999999, 3),
+            range(5000000, 7999999, 4),
+            range(8000000, 8999999, 5),
+            range(9000000, 9999999, 6),
+        ],
+    },
+    Group {
+        prefix: "978-80",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 5299999, 3),
+            range(5300000, 5499999, 5),
+            range(5500000, 6899999, 3),
+            range(6900000, 6999999, 5),
+            range(7000000, 8499999, 4),
+            range(8500000, 8999999, 5),
+            range(9000000, 9989999, 6),
+            range(9990000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-81",
+        registrants: &[
+            range(0, 1899999, 2),
+            range(1900000, 1999999, 5),
+            range(2000000, 6999999, 3),
+            range(7000000, 8499999, 4),
+            range(8500000, 8999999, 5),
+            range(9000000, 9999999, 6),
+        ],
+    },
+    Group {
+        prefix: "978-82",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 6899999, 3),
+            range(6900000, 6999999, 6),
+            range(7000000, 8999999, 4),
+            range(9000000, 9899999, 5),
+            range(9900000, 9999999, 6),
+        ],
+    },
+    Group {
+        prefix: "978-83",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 5999999, 3),
+            range(6000000, 6999999, 5),
+            range(7000000, 8499999, 4),
+            range(8500000, 8999999, 5),
+            range(9000000, 9999999, 6),
+        ],
+    },
+    Group {
+        prefix: "978-84",
+        registrants: &[
+            range(0, 999999, 2),
+            range(1000000, 1049999, 5),
+            range(1050000, 1199999, 4),
+            range(1200000, 1299999, 6),
+            range(1300000, 1399999, 4),
+            range(1400000, 1499999, 3),
+            range(1500000, 1999999, 5),
+            range(2000000, 6999999, 3),
+            range(7000000, 8499999, 4),
+            range(8500000, 8999999, 5),
+            range(9000000, 9199999, 4),
+            range(9200000, 9239999, 6),
+            range(9240000, 9299999, 5),
+            range(9300000, 9499999, 6),
+            range(9500000, 9699999, 5),
+            range(9700000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-85",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 4549999, 3),
+            range(4550000, 4552999, 6),
+            range(4553000, 4559999, 5),
+            range(4560000, 5289999, 3),
+            range(5290000, 5319999, 5),
+            range(5320000, 5339999, 4),
+            range(5340000, 5399999, 3),
+            range(5400000, 5402999, 5),
+            range(5403000, 5403999, 5),
+            range(5404000, 5404999, 6),
+            range(5405000, 5408999, 5),
+            range(5409000, 5409999, 6),
+            range(5410000, 5439999, 5),
+            range(5440000, 5479999, 4),
+            range(5480000, 5499999, 5),
+            range(5500000, 5999999, 4),
+            range(6000000, 6999999, 5),
+            range(7000000, 8499999, 4),
+            range(8500000, 8999999, 5),
+            range(9000000, 9249999, 6),
+            range(9250000, 9449999, 5),
+            range(9450000, 9599999, 4),
+            range(9600000, 9799999, 2),
+            range(9800000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-86",
+        registrants: &[
+            range(0, 2999999, 2),
+            range(3000000, 5999999, 3),
+            range(6000000, 7999999, 4),
+            range(8000000, 8999999, 5),
+            range(9000000, 9999999, 6),
+        ],
+    },
+    Group {
+        prefix: "978-87",
+        registrants: &[
+            range(0, 2999999, 2),
+            range(3000000, 3999999, 0),
+            range(4000000, 6499999, 3),
+            range(6500000, 6999999, 0),
+            range(7000000, 7999999, 4),
+            range(8000000, 8499999, 0),
+            range(8500000, 9499999, 5),
+            range(9500000, 9699999, 0),
+            range(9700000, 9999999, 6),
+        ],
+    },
+    Group {
+        prefix: "978-88",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 3119999, 3),
+            range(3120000, 3149999, 5),
+            range(3150000, 3184999, 6),
+            range(3185000, 3189999, 5),
+            range(3190000, 5999999, 3),
+            range(6000000, 8499999, 4),
+            range(8500000, 8999999, 5),
+            range(9000000, 9099999, 6),
+            range(9100000, 9269999, 3),
+            range(9270000, 9399999, 4),
+            range(9400000, 9479999, 6),
+            range(9480000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-89",
+        registrants: &[
+            range(0, 2499999, 2),
+            range(2500000, 5499999, 3),
+            range(5500000, 8499999, 4),
+            range(8500000, 9499999, 5),
+            range(9500000, 9699999, 6),
+            range(9700000, 9899999, 5),
+            range(9900000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-90",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 4999999, 3),
+            range(5000000, 6999999, 4),
+            range(7000000, 7999999, 5),
+            range(8000000, 8499999, 6),
+            range(8500000, 8999999, 4),
+            range(9000000, 9099999, 2),
+            range(9100000, 9399999, 0),
+            range(9400000, 9499999, 2),
+            range(9500000, 9999999, 0),
+        ],
+    },
+    Group {
+        prefix: "978-91",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 4999999, 2),
+            range(5000000, 6499999, 3),
+            range(6500000, 6999999, 0),
+            range(7000000, 8199999, 4),
+            range(8200000, 8499999, 0),
+            range(8500000, 9499999, 4),
+            range(9500000, 9699999, 0),
+            range(9700000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-92",
+        registrants: &[
+            range(0, 5999999, 1),
+            range(6000000, 7999999, 2),
+            range(8000000, 8999999, 3),
+            range(9000000, 9499999, 4),
+            range(9500000, 9899999, 5),
+            range(9900000, 9999999, 6),
+        ],
+    },
+    Group {
+        prefix: "978-93",
+        registrants: &[
+            range(0, 999999, 2),
+            range(1000000, 4999999, 3),
+            range(5000000, 7999999, 4),
+            range(8000000, 9599999, 5),
+            range(9600000, 9999999, 6),
+        ],
+    },
+    Group {
+        prefix: "978-94",
+        registrants: &[
+            range(0, 5999999, 3),
+            range(6000000, 8999999, 4),
+            range(9000000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-950",
+        registrants: &[
+            range(0, 4999999, 2),
+            range(5000000, 8999999, 3),
+            range(9000000, 9899999, 4),
+            range(9900000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-951",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 5499999, 2),
+            range(5500000, 8899999, 3),
+            range(8900000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-952",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 4999999, 3),
+            range(5000000, 5999999, 4),
+            range(6000000, 6499999, 2),
+            range(6500000, 6599999, 5),
+            range(6600000, 6699999, 4),
+            range(6700000, 6999999, 5),
+            range(7000000, 7999999, 4),
+            range(8000000, 9499999, 2),
+            range(9500000, 9899999, 4),
+            range(9900000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-953",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 1499999, 2),
+            range(1500000, 4799999, 3),
+            range(4800000, 4999999, 5),
+            range(5000000, 5009999, 3),
+            range(5010000, 5099999, 5),
+            range(5100000, 5499999, 2),
+            range(5500000, 5999999, 5),
+            range(6000000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-954",
+        registrants: &[
+            range(0, 2899999, 2),
+            range(2900000, 2999999, 4),
+            range(3000000, 7999999, 3),
+            range(8000000, 8999999, 4),
+            range(9000000, 9299999, 5),
+            range(9300000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-955",
+        registrants: &[
+            range(0, 1999999, 4),
+            range(2000000, 3399999, 2),
+            range(3400000, 3549999, 4),
+            range(3550000, 3599999, 5),
+            range(3600000, 3799999, 4),
+            range(3800000, 3899999, 5),
+            range(3900000, 4099999, 4),
+            range(4100000, 4499999, 5),
+            range(4500000, 4999999, 4),
+            range(5000000, 5499999, 5),
+            range(5500000, 7109999, 3),
+            range(7110000, 7149999, 5),
+            range(7150000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-956",
+        registrants: &[
+            range(0, 899999, 2),
+            range(900000, 999999, 5),
+            range(1000000, 1999999, 2),
+            range(2000000, 5999999, 3),
+            range(6000000, 6999999, 4),
+            range(7000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-957",
+        registrants: &[
+            range(0, 299999, 2),
+            range(300000, 499999, 4),
+            range(500000, 1999999, 2),
+            range(2000000, 2099999, 4),
+            range(2100000, 2799999, 2),
+            range(2800000, 3099999, 5),
+            range(3100000, 4399999, 2),
+            range(4400000, 8199999, 3),
+            range(8200000, 9699999, 4),
+            range(9700000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-958",
+        registrants: &[
+            range(0, 4999999, 2),
+            range(5000000, 5099999, 3),
+            range(5100000, 5199999, 4),
+            range(5200000, 5399999, 5),
+            range(5400000, 5599999, 4),
+            range(5600000, 5999999, 5),
+            range(6000000, 7999999, 3),
+            range(8000000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-959",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 6999999, 3),
+            range(7000000, 8499999, 4),
+            range(8500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-960",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 6599999, 3),
+            range(6600000, 6899999, 4),
+            range(6900000, 6999999, 3),
+            range(7000000, 8499999, 4),
+            range(8500000, 9299999, 5),
+            range(9300000, 9399999, 2),
+            range(9400000, 9799999, 4),
+            range(9800000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-961",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 5999999, 3),
+            range(6000000, 8999999, 4),
+            range(9000000, 9799999, 5),
+            range(9800000, 9999999, 0),
+        ],
+    },
+    Group {
+        prefix: "978-962",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 6999999, 3),
+            range(7000000, 8499999, 4),
+            range(8500000, 8699999, 5),
+            range(8700000, 8999999, 4),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-963",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 6999999, 3),
+            range(7000000, 8499999, 4),
+            range(8500000, 8999999, 5),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-964",
+        registrants: &[
+            range(0, 1499999, 2),
+            range(1500000, 2499999, 3),
+            range(2500000, 2999999, 4),
+            range(3000000, 5499999, 3),
+            range(5500000, 8999999, 4),
+            range(9000000, 9699999, 5),
+            range(9700000, 9899999, 3),
+            range(9900000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-965",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 5999999, 3),
+            range(6000000, 6999999, 0),
+            range(7000000, 7999999, 4),
+            range(8000000, 8999999, 0),
+            range(9000000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-966",
+        registrants: &[
+            range(0, 1299999, 2),
+            range(1300000, 1399999, 3),
+            range(1400000, 1499999, 2),
+            range(1500000, 1699999, 4),
+            range(1700000, 1999999, 3),
+            range(2000000, 2789999, 4),
+            range(2900000, 6999999, 3),
+            range(7000000, 8999999, 4),
+            range(9000000, 9099999, 5),
+            range(9100000, 9499999, 3),
+            range(9500000, 9799999, 5),
+            range(9800000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-967",
+        registrants: &[
+            range(0, 99999, 2),
+            range(100000, 999999, 4),
+            range(1000000, 1999999, 5),
+            range(2000000, 2499999, 4),
+            range(2500000, 2549999, 0),
+            range(2550000, 2699999, 3),
+            range(2700000, 2799999, 4),
+            range(2800000, 2899999, 5),
+            range(2900000, 2999999, 5),
+            range(3000000, 4999999, 4),
+            range(5000000, 8999999, 3),
+            range(9000000, 9899999, 3),
+            range(9900000, 9989999, 4),
+            range(9990000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-968",
+        registrants: &[
+            range(100000, 3999999, 2),
+            range(4000000, 4999999, 3),
+            range(5000000, 7999999, 4),
+            range(8000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-969",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 2299999, 2),
+            range(2300000, 2399999, 5),
+            range(2400000, 3999999, 2),
+            range(4000000, 7499999, 3),
+            range(7500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-970",
+        registrants: &[
+            range(100000, 5999999, 2),
+            range(6000000, 8999999, 3),
+            range(9000000, 9099999, 4),
+            range(9100000, 9699999, 5),
+            range(9700000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-971",
+        registrants: &[
+            range(0, 159999, 3),
+            range(160000, 199999, 4),
+            range(200000, 299999, 2),
+            range(300000, 599999, 4),
+            range(600000, 4999999, 2),
+            range(5000000, 8499999, 3),
+            range(8500000, 9099999, 4),
+            range(9100000, 9599999, 5),
+            range(9600000, 9699999, 4),
+            range(9700000, 9899999, 2),
+            range(9900000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-972",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 5499999, 2),
+            range(5500000, 7999999, 3),
+            range(8000000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-973",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 1699999, 3),
+            range(1700000, 1999999, 4),
+            range(2000000, 5499999, 2),
+            range(5500000, 7599999, 3),
+            range(7600000, 8499999, 4),
+            range(8500000, 8899999, 5),
+            range(8900000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-974",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 6999999, 3),
+            range(7000000, 8499999, 4),
+            range(8500000, 8999999, 5),
+            range(9000000, 9499999, 5),
+            range(9500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-975",
+        registrants: &[
+            range(0, 199999, 5),
+            range(200000, 2499999, 2),
+            range(2500000, 5999999, 3),
+            range(6000000, 9199999, 4),
+            range(9200000, 9899999, 5),
+            range(9900000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-976",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 5999999, 2),
+            range(6000000, 7999999, 3),
+            range(8000000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-977",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 4999999, 3),
+            range(5000000, 6999999, 4),
+            range(7000000, 8499999, 3),
+            range(8500000, 8929999, 5),
+            range(8930000, 8949999, 3),
+            range(8950000, 8999999, 4),
+            range(9000000, 9899999, 2),
+            range(9900000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-978",
+        registrants: &[
+            range(0, 1999999, 3),
+            range(2000000, 2999999, 4),
+            range(3000000, 7999999, 5),
+            range(8000000, 8999999, 4),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-979",
+        registrants: &[
+            range(0, 999999, 3),
+            range(1000000, 1499999, 4),
+            range(1500000, 1999999, 5),
+            range(2000000, 2999999, 2),
+            range(3000000, 3999999, 4),
+            range(4000000, 7999999, 3),
+            range(8000000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-980",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 5999999, 3),
+            range(6000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-981",
+        registrants: &[
+            range(0, 1699999, 2),
+            range(1700000, 1799999, 5),
+            range(1800000, 1999999, 2),
+            range(2000000, 2999999, 3),
+            range(3000000, 3099999, 4),
+            range(3100000, 3999999, 3),
+            range(4000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-982",
+        registrants: &[
+            range(0, 999999, 2),
+            range(1000000, 6999999, 3),
+            range(7000000, 8999999, 2),
+            range(9000000, 9799999, 4),
+            range(9800000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-983",
+        registrants: &[
+            range(0, 199999, 2),
+            range(200000, 1999999, 3),
+            range(2000000, 3999999, 4),
+            range(4000000, 4499999, 5),
+            range(4500000, 4999999, 2),
+            range(5000000, 7999999, 2),
+            range(8000000, 8999999, 3),
+            range(9000000, 9899999, 4),
+            range(9900000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-984",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 7999999, 3),
+            range(8000000, 8999999, 4),
+            range(9000000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-985",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 5999999, 3),
+            range(6000000, 8799999, 4),
+            range(8800000, 8999999, 3),
+            range(9000000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-986",
+        registrants: &[
+            range(0, 599999, 2),
+            range(600000, 799999, 3),
+            range(800000, 1199999, 2),
+            range(1200000, 5399999, 3),
+            range(5400000, 7999999, 4),
+            range(8000000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-987",
+        registrants: &[
+            range(0, 999999, 2),
+            range(1000000, 1999999, 4),
+            range(2000000, 2999999, 5),
+            range(3000000, 3599999, 2),
+            range(3600000, 4199999, 4),
+            range(4200000, 4399999, 2),
+            range(4400000, 4499999, 4),
+            range(4500000, 4899999, 5),
+            range(4900000, 4999999, 4),
+            range(5000000, 8299999, 3),
+            range(8300000, 8499999, 4),
+            range(8500000, 8899999, 2),
+            range(8900000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-988",
+        registrants: &[
+            range(0, 1199999, 2),
+            range(1200000, 1999999, 5),
+            range(2000000, 6999999, 3),
+            range(7000000, 7999999, 5),
+            range(8000000, 9699999, 4),
+            range(9700000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-989",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 3499999, 2),
+            range(3500000, 3699999, 5),
+            range(3700000, 5299999, 2),
+            range(5300000, 5499999, 5),
+            range(5500000, 7999999, 3),
+            range(8000000, 9499999, 4),
+            range(9500000, 9999999, 5),
+        ],
+    },
+    Group {
+        prefix: "978-9917",
+        registrants: &[
+            range(0, 999999, 1),
+            range(3000000, 3499999, 2),
+            range(6000000, 6999999, 3),
+            range(9700000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9918",
+        registrants: &[
+            range(0, 999999, 1),
+            range(2000000, 2999999, 2),
+            range(6000000, 7999999, 3),
+            range(9500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9919",
+        registrants: &[
+            range(0, 999999, 1),
+            range(2000000, 2799999, 2),
+            range(5000000, 5999999, 3),
+            range(9500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9920",
+        registrants: &[
+            range(3200000, 3999999, 2),
+            range(5500000, 7999999, 3),
+            range(8750000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9921",
+        registrants: &[
+            range(0, 999999, 1),
+            range(3000000, 3999999, 2),
+            range(7000000, 8999999, 3),
+            range(9700000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9922",
+        registrants: &[
+            range(2000000, 2999999, 2),
+            range(6000000, 7999999, 3),
+            range(8500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9923",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 6999999, 2),
+            range(7000000, 8999999, 3),
+            range(9400000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9924",
+        registrants: &[
+            range(3000000, 3999999, 2),
+            range(5000000, 6599999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9925",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 5499999, 2),
+            range(5500000, 7349999, 3),
+            range(7350000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9926",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 3999999, 2),
+            range(4000000, 7999999, 3),
+            range(8000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9927",
+        registrants: &[
+            range(0, 999999, 2),
+            range(1000000, 3999999, 3),
+            range(4000000, 4999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9928",
+        registrants: &[
+            range(0, 999999, 2),
+            range(1000000, 3999999, 3),
+            range(4000000, 4999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9929",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 5499999, 2),
+            range(5500000, 7999999, 3),
+            range(8000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9930",
+        registrants: &[
+            range(0, 4999999, 2),
+            range(5000000, 9399999, 3),
+            range(9400000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9931",
+        registrants: &[
+            range(0, 2999999, 2),
+            range(3000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9932",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 8499999, 3),
+            range(8500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9933",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 3999999, 2),
+            range(4000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9934",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 4999999, 2),
+            range(5000000, 7999999, 3),
+            range(8000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9935",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 3999999, 2),
+            range(4000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9936",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 3999999, 2),
+            range(4000000, 7999999, 3),
+            range(8000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9937",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 4999999, 2),
+            range(5000000, 7999999, 3),
+            range(8000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9938",
+        registrants: &[
+            range(0, 7999999, 2),
+            range(8000000, 9499999, 3),
+            range(9500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9939",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 7999999, 2),
+            range(8000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9940",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 4999999, 2),
+            range(5000000, 8399999, 3),
+            range(8400000, 8699999, 2),
+            range(8700000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9941",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 3999999, 2),
+            range(4000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9942",
+        registrants: &[
+            range(0, 5999999, 2),
+            range(6000000, 6999999, 3),
+            range(7000000, 7499999, 4),
+            range(7500000, 8499999, 3),
+            range(8500000, 8999999, 4),
+            range(9000000, 9849999, 3),
+            range(9850000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9943",
+        registrants: &[
+            range(0, 2999999, 2),
+            range(3000000, 3999999, 3),
+            range(4000000, 9749999, 4),
+            range(9750000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-9944",
+        registrants: &[
+            range(0, 999999, 4),
+            range(1000000, 4999999, 3),
+            range(5000000, 5999999, 4),
+            range(6000000, 6999999, 2),
+            range(7000000, 7999999, 3),
+            range(8000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-9945",
+        registrants: &[
+            range(0, 99999, 2),
+            range(100000, 799999, 3),
+            range(800000, 3999999, 2),
+            range(4000000, 5699999, 3),
+            range(5700000, 5799999, 2),
+            range(5800000, 8499999, 3),
+            range(8500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9946",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 3999999, 2),
+            range(4000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9947",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-9948",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 8499999, 3),
+            range(8500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9949",
+        registrants: &[
+            range(0, 899999, 2),
+            range(900000, 999999, 3),
+            range(1000000, 3999999, 2),
+            range(4000000, 7499999, 3),
+            range(7500000, 8999999, 2),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9950",
+        registrants: &[
+            range(0, 2999999, 2),
+            range(3000000, 8499999, 3),
+            range(8500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9951",
+        registrants: &[
+            range(0, 3899999, 2),
+            range(3900000, 8499999, 3),
+            range(8500000, 9799999, 4),
+            range(9800000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-9952",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 3999999, 2),
+            range(4000000, 7999999, 3),
+            range(8000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9953",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 3999999, 2),
+            range(4000000, 5999999, 3),
+            range(6000000, 8999999, 2),
+            range(9000000, 9299999, 4),
+            range(9300000, 9699999, 2),
+            range(9700000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-9954",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 3999999, 2),
+            range(4000000, 7999999, 3),
+            range(8000000, 9899999, 4),
+            range(9900000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-9955",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 9299999, 3),
+            range(9300000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9956",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 3999999, 2),
+            range(4000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9957",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 6499999, 3),
+            range(6500000, 6799999, 2),
+            range(6800000, 6999999, 3),
+            range(7000000, 8499999, 2),
+            range(8500000, 8799999, 4),
+            range(8800000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-9958",
+        registrants: &[
+            range(0, 199999, 2),
+            range(200000, 299999, 3),
+            range(300000, 399999, 4),
+            range(400000, 899999, 3),
+            range(900000, 999999, 4),
+            range(1000000, 1899999, 2),
+            range(1900000, 1999999, 4),
+            range(2000000, 4999999, 2),
+            range(5000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9959",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 7999999, 2),
+            range(8000000, 9499999, 3),
+            range(9500000, 9699999, 4),
+            range(9700000, 9799999, 3),
+            range(9800000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-9960",
+        registrants: &[
+            range(0, 5999999, 2),
+            range(6000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9961",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 6999999, 2),
+            range(7000000, 9499999, 3),
+            range(9500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9962",
+        registrants: &[
+            range(0, 5499999, 2),
+            range(5500000, 5599999, 4),
+            range(5600000, 5999999, 2),
+            range(6000000, 8499999, 3),
+            range(8500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9963",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 2499999, 4),
+            range(2500000, 2799999, 3),
+            range(2800000, 2999999, 4),
+            range(3000000, 5499999, 2),
+            range(5500000, 7349999, 3),
+            range(7350000, 7499999, 4),
+            range(7500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9964",
+        registrants: &[
+            range(0, 6999999, 1),
+            range(7000000, 9499999, 2),
+            range(9500000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-9965",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9966",
+        registrants: &[
+            range(0, 1399999, 3),
+            range(1400000, 1499999, 2),
+            range(1500000, 1999999, 4),
+            range(2000000, 6999999, 2),
+            range(7000000, 7499999, 4),
+            range(7500000, 8209999, 3),
+            range(8210000, 8249999, 4),
+            range(8250000, 8259999, 3),
+            range(8260000, 8289999, 4),
+            range(8290000, 9599999, 3),
+            range(9600000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9967",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9968",
+        registrants: &[
+            range(0, 4999999, 2),
+            range(5000000, 9399999, 3),
+            range(9400000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9970",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9971",
+        registrants: &[
+            range(0, 5999999, 1),
+            range(6000000, 8999999, 2),
+            range(9000000, 9899999, 3),
+            range(9900000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9972",
+        registrants: &[
+            range(0, 999999, 2),
+            range(1000000, 1999999, 1),
+            range(2000000, 2499999, 3),
+            range(2500000, 2999999, 4),
+            range(3000000, 5999999, 2),
+            range(6000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9973",
+        registrants: &[
+            range(0, 599999, 2),
+            range(600000, 899999, 3),
+            range(900000, 999999, 4),
+            range(1000000, 6999999, 2),
+            range(7000000, 9699999, 3),
+            range(9700000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9974",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 5499999, 2),
+            range(5500000, 7499999, 3),
+            range(7500000, 8799999, 4),
+            range(8800000, 9099999, 3),
+            range(9100000, 9499999, 2),
+            range(9500000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-9975",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 2999999, 3),
+            range(3000000, 3999999, 4),
+            range(4000000, 4499999, 4),
+            range(4500000, 8999999, 2),
+            range(9000000, 9499999, 3),
+            range(9500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9976",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 5899999, 4),
+            range(5900000, 8999999, 2),
+            range(9000000, 9899999, 3),
+            range(9900000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9977",
+        registrants: &[
+            range(0, 8999999, 2),
+            range(9000000, 9899999, 3),
+            range(9900000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9978",
+        registrants: &[
+            range(0, 2999999, 2),
+            range(3000000, 3999999, 3),
+            range(4000000, 9499999, 2),
+            range(9500000, 9899999, 3),
+            range(9900000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9979",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 6499999, 2),
+            range(6500000, 6599999, 3),
+            range(6600000, 7599999, 2),
+            range(7600000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9980",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 8999999, 2),
+            range(9000000, 9899999, 3),
+            range(9900000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9981",
+        registrants: &[
+            range(0, 999999, 2),
+            range(1000000, 1599999, 3),
+            range(1600000, 1999999, 4),
+            range(2000000, 7999999, 2),
+            range(8000000, 9499999, 3),
+            range(9500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9982",
+        registrants: &[
+            range(0, 7999999, 2),
+            range(8000000, 9899999, 3),
+            range(9900000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9983",
+        registrants: &[
+            range(8000000, 9499999, 2),
+            range(9500000, 9899999, 3),
+            range(9900000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9984",
+        registrants: &[
+            range(0, 4999999, 2),
+            range(5000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9985",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 7999999, 2),
+            range(8000000, 8999999, 3),
+            range(9000000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9986",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 8999999, 3),
+            range(9000000, 9399999, 4),
+            range(9400000, 9699999, 3),
+            range(9700000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-9987",
+        registrants: &[
+            range(0, 3999999, 2),
+            range(4000000, 8799999, 3),
+            range(8800000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9988",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 5499999, 2),
+            range(5500000, 7499999, 3),
+            range(7500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-9989",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 1999999, 3),
+            range(2000000, 2999999, 4),
+            range(3000000, 5999999, 2),
+            range(6000000, 9499999, 3),
+            range(9500000, 9999999, 4),
+        ],
+    },
+    Group {
+        prefix: "978-99901",
+        registrants: &[
+            range(0, 4999999, 2),
+            range(5000000, 7999999, 3),
+            range(8000000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-99903",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99904",
+        registrants: &[
+            range(0, 5999999, 1),
+            range(6000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99905",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99906",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 5999999, 2),
+            range(6000000, 6999999, 3),
+            range(7000000, 8999999, 2),
+            range(9000000, 9499999, 2),
+            range(9500000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99908",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99909",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 9499999, 2),
+            range(9500000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99910",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99911",
+        registrants: &[
+            range(0, 5999999, 2),
+            range(6000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99912",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 5999999, 3),
+            range(6000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99913",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 3599999, 2),
+            range(6000000, 6049999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99914",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99915",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99916",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 6999999, 2),
+            range(7000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99917",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99918",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99919",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 3999999, 3),
+            range(4000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99920",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99921",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 6999999, 2),
+            range(7000000, 7999999, 3),
+            range(8000000, 8999999, 1),
+            range(9000000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-99922",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 6999999, 2),
+            range(7000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99923",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99924",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99925",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99926",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 5999999, 2),
+            range(6000000, 8699999, 3),
+            range(8700000, 8999999, 2),
+            range(9000000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-99927",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 5999999, 2),
+            range(6000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99928",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99929",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99930",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99931",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99932",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 5999999, 2),
+            range(6000000, 6999999, 3),
+            range(7000000, 7999999, 1),
+            range(8000000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-99933",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 5999999, 2),
+            range(6000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99934",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99935",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 5999999, 2),
+            range(6000000, 6999999, 3),
+            range(7000000, 8999999, 1),
+            range(9000000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-99936",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 5999999, 2),
+            range(6000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99937",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 5999999, 2),
+            range(6000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99938",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 5999999, 2),
+            range(6000000, 8999999, 3),
+            range(9000000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-99939",
+        registrants: &[
+            range(0, 5999999, 1),
+            range(6000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99940",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 6999999, 2),
+            range(7000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99941",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99942",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99943",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 5999999, 2),
+            range(6000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99944",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99945",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99946",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 5999999, 2),
+            range(6000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99947",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 6999999, 2),
+            range(7000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99948",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99949",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99950",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99951",
+        registrants: &[
+            range(0, 9999999, 0),
+        ],
+    },
+    Group {
+        prefix: "978-99952",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99953",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 7999999, 2),
+            range(8000000, 9399999, 3),
+            range(9400000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-99954",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 6999999, 2),
+            range(7000000, 8799999, 3),
+            range(8800000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-99955",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 5999999, 2),
+            range(6000000, 7999999, 3),
+            range(8000000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-99956",
+        registrants: &[
+            range(0, 5999999, 2),
+            range(6000000, 8599999, 3),
+            range(8600000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-99957",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99958",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 9399999, 2),
+            range(9400000, 9499999, 3),
+            range(9500000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99959",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 5999999, 2),
+            range(6000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99960",
+        registrants: &[
+            range(0, 699999, 0),
+            range(700000, 999999, 3),
+            range(1000000, 9499999, 2),
+            range(9500000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99961",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 3699999, 3),
+            range(3700000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99962",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99963",
+        registrants: &[
+            range(0, 4999999, 2),
+            range(5000000, 9199999, 3),
+            range(9200000, 9999999, 2),
+        ],
+    },
+    Group {
+        prefix: "978-99964",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99965",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 3599999, 3),
+            range(3600000, 6299999, 2),
+            range(6300000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99966",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 6999999, 2),
+            range(7000000, 7999999, 3),
+            range(8000000, 9499999, 2),
+            range(9500000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99967",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 5999999, 2),
+            range(6000000, 8999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99968",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 5999999, 3),
+            range(6000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99969",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99970",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99971",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 8499999, 2),
+            range(8500000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99972",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99973",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99974",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 2599999, 2),
+            range(2600000, 3999999, 3),
+            range(4000000, 6399999, 2),
+            range(6400000, 6499999, 3),
+            range(6500000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99975",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(3000000, 3999999, 3),
+            range(4000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99976",
+        registrants: &[
+            range(0, 999999, 1),
+            range(1000000, 1599999, 2),
+            range(1600000, 1999999, 3),
+            range(2000000, 5999999, 2),
+            range(6000000, 8199999, 3),
+            range(8200000, 8999999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99977",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(4000000, 6999999, 2),
+            range(7000000, 7999999, 3),
+            range(9750000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99978",
+        registrants: &[
+            range(0, 4999999, 1),
+            range(5000000, 6999999, 2),
+            range(7000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99979",
+        registrants: &[
+            range(0, 3999999, 1),
+            range(4000000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99980",
+        registrants: &[
+            range(0, 999999, 1),
+            range(3000000, 5999999, 2),
+            range(7500000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99981",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 2699999, 3),
+            range(2700000, 7499999, 2),
+            range(7500000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99982",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(5000000, 6899999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99983",
+        registrants: &[
+            range(0, 999999, 1),
+            range(5000000, 6999999, 2),
+            range(9500000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99984",
+        registrants: &[
+            range(0, 999999, 1),
+            range(5000000, 6999999, 2),
+            range(9500000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99985",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(2000000, 2999999, 3),
+            range(3500000, 7999999, 2),
+            range(8000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99986",
+        registrants: &[
+            range(0, 999999, 1),
+            range(5000000, 6999999, 2),
+            range(9500000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99987",
+        registrants: &[
+            range(7000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99988",
+        registrants: &[
+            range(0, 999999, 1),
+            range(5000000, 5499999, 2),
+            range(8000000, 8249999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99989",
+        registrants: &[
+            range(0, 999999, 1),
+            range(5000000, 6499999, 2),
+            range(9000000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99990",
+        registrants: &[
+            range(0, 999999, 1),
+            range(5000000, 5799999, 2),
+            range(9600000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99992",
+        registrants: &[
+            range(0, 1999999, 1),
+            range(5000000, 6499999, 2),
+            range(9500000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "978-99993",
+        registrants: &[
+            range(0, 2999999, 1),
+            range(5000000, 5499999, 2),
+            range(9800000, 9999999, 3),
+        ],
+    },
+    Group {
+        prefix: "979-10",
+        registrants: &[
+            range(0, 1999999, 2),
+            range(2000000, 6999999, 3),
+            range(7000000, 8999999, 4),
+            range(9000000, 9759999, 5),
+            range(9760000, 9999999, 6),
+        ],
+    },
+    Group {
+        prefix: "979-11",
+        registrants: &[
+            range(0, 2499999, 2),
+            range(2500000, 5499999, 3),
+            range(5500000, 8499999, 4),
+            range(8500000, 9499999, 5),
+            range(9500000, 9999999, 6),
+        ],
+    },
+    Group {
+        prefix: "979-12",
+        registrants: &[
+            range(0, 1999999, 0),
+            range(2000000, 2999999, 3),
+            range(3000000, 5449999, 0),
+            range(5450000, 5999999, 4),
+            range(6000000, 7999999, 0),
+            range(8000000, 8499999, 5),
+            range(8500000, 9849999, 0),
+            range(9850000, 9999999, 6),
+        ],
+    },
+    Group {
+        prefix: "979-13",
+        registrants: &[
+            range(0, 99999, 2),
+            range(6000000, 6049999, 3),
+            range(7000000, 7349999, 4),
+            range(8750000, 8999999, 5),
+            range(9000000, 9999999, 6),
+        ],
+    },
+    Group {
+        prefix: "979-8",
+        registrants: &[
+            range(0, 1999999, 0),
+            range(2000000, 2299999, 3),
+            range(2300000, 3499999, 0),
+            range(3500000, 3999999, 4),
+            range(4000000, 8499999, 4),
+            range(8500000, 8849999, 4),
+            range(8850000, 8999999, 5),
+            range(9000000, 9849999, 0),
+            range(9850000, 9899999, 7),
+            range(9900000, 9999999, 0),
+        ],
+    },
+];
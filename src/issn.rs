@@ -1,5 +1,17 @@
-use crate::traits::{Valid, Normalize};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use crate::error::ParseError;
+use crate::label::{self, Label};
+use crate::traits::{Identifier, IdentifierKind, Normalize, Valid};
+use crate::{unicode, validate};
+
+#[derive(Debug, Clone)]
 pub struct ISSN {
     pub identifier: String,
 }
@@ -15,79 +27,194 @@ impl ISSN {
     /// assert_eq!(ISSN::new("0378-5955").checkdigit(), '5');
     /// ```
     pub fn checkdigit(&self) -> char {
-        let clean_string = &self.identifier.replace("-", "");
-        let first_seven = clean_string.chars().take(7);
-        let first_seven_digits = first_seven.filter_map(|x| x.to_digit(10));
-        let multiplied = first_seven_digits.enumerate().map(|(index, digit)| digit * (8 - index as u32));
+        char::from(validate::issn_weighted_check_digit(self.cleaned().as_bytes()))
+    }
+
+    /// The label in front of the ISSN, such as "eISSN" or "ISSN (print)"
+    ///
+    /// ```
+    /// use library_stdnums::label::Medium;
+    /// use library_stdnums::{Valid, ISSN};
+    /// let issn = ISSN::new("e-ISSN: 1043-383X");
+    /// assert_eq!(issn.label().unwrap().medium, Some(Medium::Electronic));
+    /// assert!(issn.valid());
+    /// ```
+    pub fn label(&self) -> Option<Label> {
+        label::parse(&unicode::clean(&self.identifier))
+            .map(|(label, _)| label)
+            .filter(|label| label.kind == IdentifierKind::ISSN)
+    }
 
-        let summed: u32 = multiplied.sum();
-        let modulus: u32 = summed % 11;
-        from_digit_to_checkdigit(modulus)
+    // The identifier with Unicode digits, dashes and spaces folded to ASCII, and any ISSN label removed
+    fn cleaned(&self) -> Cow<'_, str> {
+        label::strip_cleaned(IdentifierKind::ISSN, unicode::clean(&self.identifier))
+    }
+
+    // Valid ISSNs compare by their normalized form, anything else by the raw identifier
+    fn comparison_key(&self) -> String {
+        self.normalize().unwrap_or_else(|| self.identifier.clone())
     }
 }
 
-impl Valid for ISSN {
-    ///```
+impl PartialEq for ISSN {
+    /// Two ISSNs are equal if they normalize to the same value
+    ///
+    /// ```
     /// use library_stdnums::ISSN;
-    /// use library_stdnums::Valid;
-    /// 
-    /// assert_eq!(ISSN::new("0378-5955").valid(), true);
-    /// assert_eq!(ISSN::new("0378-5951").valid(), false);
-    /// ```
-    fn valid(&self) -> bool {
-        let basic_issn = reduce_to_basics(&self.identifier);
-        let last_digit = match basic_issn {
-            None => return false,
-            Some(num) => num.chars().next_back().unwrap()
-        };
-        last_digit == self.checkdigit()
+    /// assert_eq!(ISSN::new("0378-5955"), ISSN::new("03785955"));
+    /// assert_ne!(ISSN::new("0378-5955"), ISSN::new("0193-4511"));
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.comparison_key() == other.comparison_key()
     }
 }
 
-impl Normalize for ISSN {
-    ///```
+impl Eq for ISSN {}
+
+impl Hash for ISSN {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.comparison_key().hash(state);
+    }
+}
+
+impl fmt::Display for ISSN {
+    /// Displays the identifier as it was given
+    ///
+    /// ```
+    /// use library_stdnums::ISSN;
+    /// assert_eq!(ISSN::new("0378-5955").to_string(), "0378-5955");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.identifier)
+    }
+}
+
+impl FromStr for ISSN {
+    type Err = ParseError;
+
+    /// Parses a valid ISSN
+    ///
+    /// ```
     /// use library_stdnums::ISSN;
-    /// use library_stdnums::Normalize;
-    /// 
-    /// assert_eq!(ISSN::new("0378-5955").normalize().unwrap(), "03785955".to_string());
-    /// assert!(ISSN::new("abcdefg").normalize().is_none());
-    /// ```
-    fn normalize(&self) -> Option<String> {
-        let basic_issn = reduce_to_basics(&self.identifier);
-        if ISSN::new(basic_issn.clone()?).valid() {
-            Some(basic_issn?)
+    /// assert!("0378-5955".parse::<ISSN>().is_ok());
+    /// assert!("0378-5951".parse::<ISSN>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let issn = ISSN::new(s);
+        if issn.valid() {
+            Ok(issn)
         } else {
-            None
+            Err(ParseError::InvalidISSN(s.to_string()))
         }
     }
 }
 
-fn from_digit_to_checkdigit(num: u32) -> char {
-    let orig_num = char::from_digit((11_u32 - num) % 11, 11).unwrap();
-    if orig_num == 'a' {
-        'X'
-    } else {
-        orig_num
+impl TryFrom<&str> for ISSN {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
     }
 }
 
-fn reduce_to_basics(issn: &str) -> Option<String> {
-    let clean_string = issn
-        .replace("-", "")
-        .replace('x', "X");
-    if clean_string.chars()
-        .rev()
-        .enumerate()
-        .all(|(index, c)| c.is_ascii_digit() || (index == 0 && c == 'X' )) {
-        Some(clean_string)
-    } else {
-        None
+impl TryFrom<String> for ISSN {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if ISSN::new(value.as_str()).valid() {
+            Ok(ISSN::new(value))
+        } else {
+            Err(ParseError::InvalidISSN(value))
+        }
+    }
+}
+
+/// The components of an ISSN
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ISSNParts {
+    pub serial: String,
+    pub check_digit: char,
+}
+
+impl Identifier for ISSN {
+    type Error = ParseError;
+    type Parts = ISSNParts;
+    const KIND: IdentifierKind = IdentifierKind::ISSN;
+
+    fn parse(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+
+    /// The eight characters of a valid ISSN, with an uppercase check digit
+    ///
+    /// ```
+    /// use library_stdnums::{Identifier, ISSN};
+    /// assert_eq!(ISSN::new("0378-5955").canonical().unwrap(), "03785955");
+    /// assert!(ISSN::new("abcdefg").canonical().is_none());
+    /// ```
+    fn canonical(&self) -> Option<String> {
+        validate::issn_normalize(self.cleaned().as_bytes()).map(|issn| issn.to_string())
+    }
+
+    ///```
+    /// use library_stdnums::{Identifier, ISSN};
+    /// assert_eq!(ISSN::new("1043383x").display().unwrap(), "1043-383X");
+    /// ```
+    fn display(&self) -> Option<String> {
+        let canonical = self.canonical()?;
+        let (first, second) = canonical.split_at_checked(4)?;
+        Some(format!("{}-{}", first, second))
+    }
+
+    /// The check digit of a valid ISSN
+    ///
+    /// [`ISSN::checkdigit`] instead calculates it from the first seven digits,
+    /// whether or not the ISSN is valid
+    ///
+    ///```
+    /// use library_stdnums::{Identifier, ISSN};
+    /// assert_eq!(ISSN::new("0378-5955").check_digit(), Some('5'));
+    /// assert_eq!(ISSN::new("0378-5951").check_digit(), None);
+    /// ```
+    fn check_digit(&self) -> Option<char> {
+        self.canonical()?.chars().next_back()
+    }
+
+    fn parts(&self) -> Option<ISSNParts> {
+        let canonical = self.canonical()?;
+        let (serial, check_digit) = canonical.split_at_checked(7)?;
+        Some(ISSNParts {
+            serial: serial.to_string(),
+            check_digit: check_digit.chars().next()?,
+        })
+    }
+
+    ///```
+    /// use library_stdnums::{Identifier, ISSN};
+    /// assert_eq!(ISSN::new("03785955").uri().unwrap(), "urn:issn:0378-5955");
+    /// ```
+    fn uri(&self) -> Option<String> {
+        Some(format!("urn:issn:{}", self.display()?))
+    }
+
+    /// Whether the ISSN has eight characters and the right check digit
+    ///
+    ///```
+    /// use library_stdnums::{Identifier, ISSN};
+    /// assert!(ISSN::new("0378-5955").is_valid());
+    /// assert!(!ISSN::new("0378-5951").is_valid());
+    /// ```
+    fn is_valid(&self) -> bool {
+        validate::issn_valid(self.cleaned().as_bytes())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::label::Medium;
+    #[cfg(feature = "std")]
+    use std::collections::HashSet;
     
     #[test]
     fn it_calculates_the_checkdigit() {
@@ -100,11 +227,9 @@ mod tests {
         assert!(ISSN::new("0193-4511").valid());
         assert!(ISSN::new("1043-383x").valid());
         assert!(!ISSN::new("0193-451X").valid());
-    }
-
-    #[test]
-    fn it_reduces_to_basic() {
-        assert!(reduce_to_basics("019X-4511").is_none());
+        assert!(!ISSN::new("").valid());
+        assert!(!ISSN::new("0193-45110").valid());
+        assert!(ISSN::new("0193\u{2013}4511").valid(), "Unicode dashes are folded");
     }
 
     #[test]
@@ -113,9 +238,56 @@ mod tests {
         assert_eq!(ISSN::new("1043-383x").normalize().unwrap(), "1043383X".to_string());
     }
 
+    #[test]
+    fn it_strips_labels() {
+        assert_eq!(ISSN::new("ISSN 0378-5955").normalize().unwrap(), "03785955");
+        assert_eq!(ISSN::new("e-ISSN: 1043-383X").normalize().unwrap(), "1043383X");
+        assert_eq!(ISSN::new("pISSN 0378-5955").checkdigit(), '5');
+        assert_eq!(ISSN::new("ISSN (online) 1043-383X").label().unwrap().medium, Some(Medium::Electronic));
+        assert_eq!(ISSN::new("ISSN-L 0378-5955").label().unwrap().medium, None);
+        assert!(!ISSN::new("ISBN 0378-5955").valid());
+    }
+
     #[test]
     fn it_returns_none_for_invalid_issns() {
         assert!(ISSN::new(String::from("abcdefg")).normalize().is_none());
         assert!(ISSN::new(String::from("XXXX-XXXX")).normalize().is_none());
     }
+
+    #[test]
+    fn it_compares_on_the_normalized_form() {
+        assert_eq!(ISSN::new("1043-383x"), ISSN::new("1043383X"));
+        assert_ne!(ISSN::new("0378-5955"), ISSN::new("0193-4511"));
+        assert_eq!(ISSN::new("abcdefg"), ISSN::new("abcdefg"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn it_hashes_on_the_normalized_form() {
+        let mut issns = HashSet::new();
+        issns.insert(ISSN::new("0378-5955"));
+        issns.insert(ISSN::new("03785955"));
+        issns.insert(ISSN::new("0193-4511"));
+        assert_eq!(issns.len(), 2);
+    }
+
+    #[test]
+    fn it_parses() {
+        assert_eq!("1043-383x".parse::<ISSN>().unwrap().identifier, "1043-383x");
+        assert_eq!("0193-451X".parse::<ISSN>(), Err(ParseError::InvalidISSN("0193-451X".to_string())));
+        assert_eq!(ISSN::try_from(String::from("0378-5955")).unwrap(), ISSN::new("03785955"));
+    }
+
+    #[test]
+    fn it_implements_identifier() {
+        let issn = ISSN::parse("1043-383x").unwrap();
+        assert_eq!(issn.kind(), IdentifierKind::ISSN);
+        assert_eq!(issn.canonical().unwrap(), "1043383X");
+        assert_eq!(issn.display().unwrap(), "1043-383X");
+        assert_eq!(issn.check_digit(), Some('X'));
+        assert_eq!(issn.uri().unwrap(), "urn:issn:1043-383X");
+        assert_eq!(issn.parts().unwrap(), ISSNParts { serial: "1043383".to_string(), check_digit: 'X' });
+        assert!(ISSN::parse("0193-451X").is_err());
+        assert_eq!(ISSN::new("0193-451X").parts(), None);
+    }
 }
@@ -0,0 +1,339 @@
+//! Labels written in front of identifiers, such as "ISBN-13:", "eISSN" or "LCCN"
+//!
+//! Catalog records, title pages and journal websites often label an identifier
+//! with its kind and the medium it identifies. [`parse`] recognizes:
+//!
+//! * the kind: "ISBN", "ISSN", "LCCN", "LC control number" and
+//!   "Library of Congress Control Number"
+//! * a medium before the kind: "eISBN", "p-ISSN", "Online-ISSN", "Print ISSN"
+//! * a length or linking suffix: "ISBN-10", "ISBN13", "ISSN-L"
+//! * a medium after the kind, bare or in parentheses, in English, French,
+//!   German, Spanish, Portuguese and Italian: "ISSN (online)", "ISSN en ligne",
+//!   "ISBN (Druckausgabe)", "ISSN impreso"
+//! * a medium in parentheses after the identifier: "ISSN 1043-383X (Print)"
+//!
+//! `ISBN`, `ISSN` and `LCCN` remove a label of their own kind before validating
+//! or normalizing an identifier.
+//!
+//! ```
+//! use library_stdnums::label::{self, Label, Medium};
+//! use library_stdnums::IdentifierKind;
+//!
+//! let (found, identifier) = label::parse("e-ISSN: 1043-383X").unwrap();
+//! assert_eq!(found, Label { kind: IdentifierKind::ISSN, medium: Some(Medium::Electronic) });
+//! assert_eq!(identifier, "1043-383X");
+//! assert_eq!(label::strip(IdentifierKind::ISBN, "ISBN-13: 978-0-306-40615-7"), "978-0-306-40615-7");
+//! ```
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
+
+use crate::traits::IdentifierKind;
+
+/// The medium a labeled identifier belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Medium {
+    Print,
+    Electronic,
+}
+
+/// A label found in front of an identifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Label {
+    /// The kind of identifier the label names
+    pub kind: IdentifierKind,
+    /// The medium, if the label gives one
+    pub medium: Option<Medium>,
+}
+
+// Names of each kind, longest first where one begins another
+const KINDS: &[(&str, IdentifierKind)] = &[
+    ("isbn", IdentifierKind::ISBN),
+    ("issn", IdentifierKind::ISSN),
+    ("lccn", IdentifierKind::LCCN),
+    ("library of congress control number", IdentifierKind::LCCN),
+    ("lc control number", IdentifierKind::LCCN),
+    ("lc control no", IdentifierKind::LCCN),
+];
+
+// Media written in front of the kind, as in "eISBN" or "Online-ISSN"
+const MEDIUM_PREFIXES: &[(&str, Medium)] = &[
+    ("electronic", Medium::Electronic),
+    ("online", Medium::Electronic),
+    ("print", Medium::Print),
+    ("e", Medium::Electronic),
+    ("p", Medium::Print),
+];
+
+// Media written after the kind or the identifier, as in "ISSN (online)" or "ISSN en ligne",
+// hyphenated words before the word they begin with
+const MEDIUMS: &[(&str, Medium)] = &[
+    ("print-ausgabe", Medium::Print),
+    ("print", Medium::Print),
+    ("printed", Medium::Print),
+    ("paper", Medium::Print),
+    ("paperback", Medium::Print),
+    ("pbk", Medium::Print),
+    ("hardback", Medium::Print),
+    ("hardcover", Medium::Print),
+    ("hbk", Medium::Print),
+    ("cloth", Medium::Print),
+    ("imprimé", Medium::Print),
+    ("imprimée", Medium::Print),
+    ("broché", Medium::Print),
+    ("relié", Medium::Print),
+    ("druck", Medium::Print),
+    ("druckausgabe", Medium::Print),
+    ("broschiert", Medium::Print),
+    ("gebunden", Medium::Print),
+    ("impreso", Medium::Print),
+    ("impresso", Medium::Print),
+    ("stampa", Medium::Print),
+    ("cartaceo", Medium::Print),
+    ("online-ausgabe", Medium::Electronic),
+    ("online", Medium::Electronic),
+    ("on-line", Medium::Electronic),
+    ("electronic", Medium::Electronic),
+    ("ebook", Medium::Electronic),
+    ("e-book", Medium::Electronic),
+    ("ebk", Medium::Electronic),
+    ("digital", Medium::Electronic),
+    ("pdf", Medium::Electronic),
+    ("epub", Medium::Electronic),
+    ("internet", Medium::Electronic),
+    ("en ligne", Medium::Electronic),
+    ("électronique", Medium::Electronic),
+    ("numérique", Medium::Electronic),
+    ("elektronisch", Medium::Electronic),
+    ("en línea", Medium::Electronic),
+    ("electrónico", Medium::Electronic),
+    ("eletrônico", Medium::Electronic),
+    ("elettronico", Medium::Electronic),
+];
+
+/// Finds a label at the start of the input
+///
+/// Returns the label and the identifier that follows it, or None if the input
+/// doesn't start with a label.
+///
+/// ```
+/// use library_stdnums::label::{self, Medium};
+/// use library_stdnums::IdentifierKind;
+///
+/// let (found, identifier) = label::parse("ISSN 0378-5955 (Online)").unwrap();
+/// assert_eq!((found.kind, found.medium), (IdentifierKind::ISSN, Some(Medium::Electronic)));
+/// assert_eq!(identifier, "0378-5955");
+/// assert_eq!(label::parse("0378-5955"), None);
+/// ```
+pub fn parse(input: &str) -> Option<(Label, &str)> {
+    let input = input.trim_start();
+    let (mut medium, rest) = match medium_prefix(input) {
+        Some((medium, rest)) => (Some(medium), rest),
+        None => (None, input),
+    };
+    let (kind, rest) = strip_kind(rest)?;
+    let rest = strip_suffix(kind, rest);
+    let rest = rest.trim_start();
+    let rest = match qualifier(rest) {
+        Some((qualifier, rest)) => {
+            medium = medium.or(qualifier);
+            rest
+        }
+        None => rest,
+    };
+    let rest = rest.trim_start_matches(|c: char| c == ':' || c == '#' || c == '.' || c.is_whitespace());
+    let rest = match trailing_qualifier(rest) {
+        Some((qualifier, rest)) => {
+            medium = medium.or(Some(qualifier));
+            rest
+        }
+        None => rest.trim_end(),
+    };
+    Some((Label { kind, medium }, rest))
+}
+
+/// Removes a label naming the given kind from the start of the input
+///
+/// The input is returned as it is if it has no label, or one naming another kind.
+///
+/// ```
+/// use library_stdnums::label;
+/// use library_stdnums::IdentifierKind;
+/// assert_eq!(label::strip(IdentifierKind::LCCN, "LC control no.: n78-890351"), "n78-890351");
+/// assert_eq!(label::strip(IdentifierKind::ISBN, "ISSN 0378-5955"), "ISSN 0378-5955");
+/// ```
+pub fn strip(kind: IdentifierKind, input: &str) -> &str {
+    match parse(input) {
+        Some((label, rest)) if label.kind == kind => rest,
+        _ => input,
+    }
+}
+
+// Removes a label from an identifier that has been cleaned with `unicode::clean`
+#[cfg(feature = "alloc")]
+pub(crate) fn strip_cleaned(kind: IdentifierKind, cleaned: Cow<'_, str>) -> Cow<'_, str> {
+    match cleaned {
+        Cow::Borrowed(input) => Cow::Borrowed(strip(kind, input)),
+        Cow::Owned(input) => {
+            let stripped = strip(kind, &input);
+            if stripped.len() == input.len() {
+                Cow::Owned(input)
+            } else {
+                Cow::Owned(stripped.to_string())
+            }
+        }
+    }
+}
+
+// A medium written in front of the kind, which must follow it
+fn medium_prefix(input: &str) -> Option<(Medium, &str)> {
+    MEDIUM_PREFIXES.iter().find_map(|&(prefix, medium)| {
+        let rest = strip_prefix_ignoring_case(input, prefix)?;
+        let rest = rest.strip_prefix(['-', ' ']).unwrap_or(rest);
+        strip_kind(rest).map(|_| (medium, rest))
+    })
+}
+
+fn strip_kind(input: &str) -> Option<(IdentifierKind, &str)> {
+    KINDS.iter().find_map(|&(name, kind)| {
+        let rest = strip_prefix_ignoring_case(input, name)?;
+        // "ISBN10" and "ISBN13" run the suffix into the kind
+        match rest.chars().next() {
+            Some(c) if c.is_alphabetic() => None,
+            _ => Some((kind, rest)),
+        }
+    })
+}
+
+// Removes "-10" or "13" from an ISBN label, or "-L" from an ISSN label. A
+// suffix after a space, as in "ISBN 13: ", needs the colon to tell it from
+// the identifier
+fn strip_suffix(kind: IdentifierKind, input: &str) -> &str {
+    let (rest, spaced) = match input.strip_prefix(' ') {
+        Some(rest) => (rest, true),
+        None => (input.strip_prefix('-').unwrap_or(input), false),
+    };
+    let suffixes: &[&str] = match kind {
+        IdentifierKind::ISBN => &["10", "13"],
+        IdentifierKind::ISSN => &["l"],
+        IdentifierKind::LCCN => &[],
+    };
+    suffixes
+        .iter()
+        .filter_map(|suffix| strip_prefix_ignoring_case(rest, suffix))
+        .find(|rest| match rest.chars().next() {
+            Some(c) if spaced => c == ':',
+            Some(c) => !c.is_alphanumeric(),
+            None => !spaced,
+        })
+        .unwrap_or(input)
+}
+
+// A medium after the kind, bare or in parentheses. Unrecognized words in
+// parentheses, such as "(v. 1)", are removed without giving a medium
+fn qualifier(input: &str) -> Option<(Option<Medium>, &str)> {
+    if let Some(inner) = input.strip_prefix('(') {
+        let end = inner.find(')')?;
+        return Some((medium(inner[..end].trim()), &inner[end + 1..]));
+    }
+    MEDIUMS.iter().find_map(|&(word, medium)| Some((Some(medium), strip_word(input, word)?)))
+}
+
+// A recognized medium in parentheses at the end of the input
+fn trailing_qualifier(input: &str) -> Option<(Medium, &str)> {
+    let inner = input.trim_end().strip_suffix(')')?;
+    let start = inner.rfind('(')?;
+    let medium = medium(inner[start + 1..].trim())?;
+    Some((medium, inner[..start].trim_end()))
+}
+
+// The medium named by the start of the text, ignoring a trailing full stop
+fn medium(text: &str) -> Option<Medium> {
+    MEDIUMS.iter().find_map(|&(word, medium)| strip_word(text, word).map(|_| medium))
+}
+
+// Removes a word from the start of the input, if another letter doesn't follow it
+fn strip_word<'a>(input: &'a str, word: &str) -> Option<&'a str> {
+    let rest = strip_prefix_ignoring_case(input, word)?;
+    match rest.chars().next() {
+        Some(c) if c.is_alphanumeric() => None,
+        _ => Some(rest),
+    }
+}
+
+// Removes a lowercase prefix from the input, ignoring the case of the input
+fn strip_prefix_ignoring_case<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    let mut chars = input.chars();
+    for expected in prefix.chars() {
+        let c = chars.next()?;
+        if !c.to_lowercase().eq([expected]) {
+            return None;
+        }
+    }
+    Some(chars.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(input: &str) -> (IdentifierKind, Option<Medium>, &str) {
+        let (label, rest) = parse(input).unwrap();
+        (label.kind, label.medium, rest)
+    }
+
+    #[test]
+    fn it_recognizes_isbn_labels() {
+        use IdentifierKind::ISBN;
+        assert_eq!(parsed("ISBN-13: 978-0-306-40615-7"), (ISBN, None, "978-0-306-40615-7"));
+        assert_eq!(parsed("ISBN10 0306406152"), (ISBN, None, "0306406152"));
+        assert_eq!(parsed("isbn 13: 9780306406157"), (ISBN, None, "9780306406157"));
+        assert_eq!(parsed("ISBN 13 9780306406157"), (ISBN, None, "13 9780306406157"));
+        assert_eq!(parsed("ISBN 1449373321"), (ISBN, None, "1449373321"));
+        assert_eq!(parsed("eISBN 9780306406157"), (ISBN, Some(Medium::Electronic), "9780306406157"));
+        assert_eq!(parsed("e-ISBN: 9780306406157"), (ISBN, Some(Medium::Electronic), "9780306406157"));
+        assert_eq!(parsed("ISBN (pbk.) 0306406152"), (ISBN, Some(Medium::Print), "0306406152"));
+        assert_eq!(parsed("ISBN 0306406152 (paperback)"), (ISBN, Some(Medium::Print), "0306406152"));
+        assert_eq!(parsed("ISBN (Druckausgabe): 9780306406157"), (ISBN, Some(Medium::Print), "9780306406157"));
+        assert_eq!(parsed("ISBN (v. 1) 0306406152"), (ISBN, None, "0306406152"));
+    }
+
+    #[test]
+    fn it_recognizes_issn_labels() {
+        use IdentifierKind::ISSN;
+        assert_eq!(parsed("ISSN 0378-5955"), (ISSN, None, "0378-5955"));
+        assert_eq!(parsed("ISSN-L 0378-5955"), (ISSN, None, "0378-5955"));
+        assert_eq!(parsed("pISSN 0378-5955"), (ISSN, Some(Medium::Print), "0378-5955"));
+        assert_eq!(parsed("eISSN 1043-383X"), (ISSN, Some(Medium::Electronic), "1043-383X"));
+        assert_eq!(parsed("Online-ISSN: 1043-383X"), (ISSN, Some(Medium::Electronic), "1043-383X"));
+        assert_eq!(parsed("Print ISSN 0378-5955"), (ISSN, Some(Medium::Print), "0378-5955"));
+        assert_eq!(parsed("ISSN (online): 1043-383X"), (ISSN, Some(Medium::Electronic), "1043-383X"));
+        assert_eq!(parsed("ISSN 1043-383X (Online)"), (ISSN, Some(Medium::Electronic), "1043-383X"));
+        assert_eq!(parsed("ISSN en ligne : 1043-383X"), (ISSN, Some(Medium::Electronic), "1043-383X"));
+        assert_eq!(parsed("ISSN électronique 1043-383X"), (ISSN, Some(Medium::Electronic), "1043-383X"));
+        assert_eq!(parsed("ISSN ÉLECTRONIQUE 1043-383X"), (ISSN, Some(Medium::Electronic), "1043-383X"));
+        assert_eq!(parsed("ISSN impreso: 0378-5955"), (ISSN, Some(Medium::Print), "0378-5955"));
+        assert_eq!(parsed("ISSN en línea 1043-383X"), (ISSN, Some(Medium::Electronic), "1043-383X"));
+        assert_eq!(parsed("ISSN Online-Ausgabe 1043-383X"), (ISSN, Some(Medium::Electronic), "1043-383X"));
+        assert_eq!(parsed("ISSN eletrônico 1043-383X"), (ISSN, Some(Medium::Electronic), "1043-383X"));
+    }
+
+    #[test]
+    fn it_recognizes_lccn_labels() {
+        use IdentifierKind::LCCN;
+        assert_eq!(parsed("LCCN n78-890351"), (LCCN, None, "n78-890351"));
+        assert_eq!(parsed("LCCN: 94014580 /AC/r95"), (LCCN, None, "94014580 /AC/r95"));
+        assert_eq!(parsed("LC control no.: n78-890351"), (LCCN, None, "n78-890351"));
+        assert_eq!(parsed("Library of Congress Control Number: 2001012345"), (LCCN, None, "2001012345"));
+    }
+
+    #[test]
+    fn it_ignores_input_without_a_label() {
+        assert_eq!(parse("0378-5955"), None);
+        assert_eq!(parse("e 2001012345"), None);
+        assert_eq!(parse("ISBNX 0306406152"), None);
+        assert_eq!(parse("pe78-890351"), None);
+        assert_eq!(strip(IdentifierKind::ISSN, "ISBN 0306406152"), "ISBN 0306406152");
+    }
+}
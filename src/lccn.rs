@@ -2,7 +2,20 @@
 /// [Library of Congress criteria](https://www.loc.gov/marc/lccn-namespace.html#syntax)
 ///
 /// If the LCCN content is valid (but not necessarily the structure), returns true
-use crate::traits::{Normalize, Valid};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use crate::error::{LCCNRuleError, ParseError};
+use crate::label::{self, Label};
+use crate::traits::{Identifier, IdentifierKind, Normalize, Valid};
+use crate::{unicode, validate};
+
+#[derive(Debug, Clone)]
 pub struct LCCN {
     pub identifier: String,
 }
@@ -11,107 +24,295 @@ impl LCCN {
     pub fn new(identifier: impl Into<String>) -> LCCN {
       LCCN {identifier: identifier.into()}
     }
-}
 
-impl Valid for LCCN {
+    /// The fixed-width form stored in MARC 010 $a
+    ///
+    /// LCCNs with a two digit year have a three character prefix, the year, the
+    /// serial number and a blank. LCCNs from 2001 on have a two character prefix,
+    /// the four digit year and the serial number. Prefixes are padded with blanks.
     ///
     /// ```
     /// use library_stdnums::LCCN;
-    /// use library_stdnums::Valid;
-    /// assert!(LCCN::new("n78-890351").valid());
-    /// assert!(LCCN::new("  2001045944").valid());
+    /// assert_eq!(LCCN::new("85-2").marc_010().unwrap(), "   85000002 ");
+    /// assert_eq!(LCCN::new("n78-890351").marc_010().unwrap(), "n  78890351 ");
+    /// assert_eq!(LCCN::new("2001-627090").marc_010().unwrap(), "  2001627090");
+    /// assert_eq!(LCCN::new("Bad LCCN").marc_010(), None);
     /// ```
+    pub fn marc_010(&self) -> Option<String> {
+        let parts = self.parts()?;
+        match parts.year.len() {
+            2 => Some(format!("{:<3}{}{} ", parts.prefix, parts.year, parts.serial)),
+            _ => Some(format!("{:<2}{}{}", parts.prefix, parts.year, parts.serial)),
+        }
+    }
+
+    /// Parses the fixed-width form of MARC 010 $a, rejecting anything else
     ///
-    /// Returns false if the LCCN content is not valid
+    /// ```
+    /// use library_stdnums::{Normalize, LCCN};
+    /// assert_eq!(LCCN::from_marc_010("n  78890351 ").unwrap().normalize().unwrap(), "n78890351");
+    /// assert_eq!(LCCN::from_marc_010("sn2003001234").unwrap().normalize().unwrap(), "sn2003001234");
+    /// assert!(LCCN::from_marc_010("n78890351").is_err());
+    /// assert!(LCCN::from_marc_010(" n 78890351 ").is_err());
+    /// ```
+    pub fn from_marc_010(field: &str) -> Result<LCCN, ParseError> {
+        let bytes = field.as_bytes();
+        // Before 2001 the last position is blank, and from 2001 it is part of the serial number
+        let (prefix, digits) = match bytes {
+            [head @ .., b' '] if bytes.len() == 12 => head.split_at(3),
+            _ if bytes.len() == 12 => bytes.split_at(2),
+            _ => return Err(ParseError::InvalidLCCN(field.to_string())),
+        };
+        let letters = prefix.iter().take_while(|byte| byte.is_ascii_lowercase()).count();
+        let padded = prefix[letters..].iter().all(|&byte| byte == b' ');
+        if padded && digits.iter().all(u8::is_ascii_digit) {
+            Ok(LCCN::new(field))
+        } else {
+            Err(ParseError::InvalidLCCN(field.to_string()))
+        }
+    }
+
+    /// Validates the LCCN against the prefixes and years the Library of Congress has used
+    ///
+    /// Four digit years must be between 2001 and the current year.
     ///
     /// ```
-    /// use library_stdnums::LCCN;
-    /// use library_stdnums::Valid;
-    /// assert!(!LCCN::new("Bad LCCN").valid());
-    /// assert_eq!(LCCN::new("Bad LCCN").valid(), false);
+    /// use library_stdnums::{LCCNRuleError, LCCN};
+    /// assert!(LCCN::new("sh85-26371").validate_strict().is_ok());
+    /// assert_eq!(LCCN::new("1850-890351").validate_strict(), Err(LCCNRuleError::ImplausibleYear("1850".to_string())));
     /// ```
-    fn valid(&self) -> bool {
-        let normalized_version = normalized_version(&self.identifier);
-        let clean = str::replace(&normalized_version, '-', "");
-        let suffix = clean.chars().rev().take(8).all(char::is_numeric);
-        if !suffix {
-            return false;
+    #[cfg(feature = "std")]
+    pub fn validate_strict(&self) -> Result<(), LCCNRuleError> {
+        self.validate_strict_as_of(current_year())
+    }
+
+    /// Validates the LCCN as [`LCCN::validate_strict`] does, in the given year
+    pub fn validate_strict_as_of(&self, current_year: u16) -> Result<(), LCCNRuleError> {
+        let parts = self.parts().ok_or_else(|| LCCNRuleError::Invalid(self.identifier.clone()))?;
+        if !parts.prefix.is_empty() && PREFIXES.binary_search(&parts.prefix.as_str()).is_err() {
+            return Err(LCCNRuleError::UnknownPrefix(parts.prefix));
         }
-    
-        match clean.len() {
-            8 => true,
-            9 => clean.chars().next().unwrap().is_alphabetic(),
-            10 => {
-                clean[..2].chars().all(char::is_numeric) || clean[..2].chars().all(char::is_alphabetic)
+        if parts.year.len() == 4 {
+            let year: u16 = parts.year.parse().unwrap_or_default();
+            if !(2001..=current_year).contains(&year) {
+                return Err(LCCNRuleError::ImplausibleYear(parts.year));
             }
-            11 => {
-                let first_char = clean.chars().next().unwrap().is_alphabetic();
-                let next_2_numeric = clean[1..3].chars().all(char::is_numeric);
-                let next_2_alpha = clean[1..3].chars().all(char::is_alphabetic);
-                first_char && (next_2_numeric || next_2_alpha)
-            }
-            12 => {
-                let first_2_alpha = clean[1..2].chars().all(char::is_alphabetic);
-                let next_2_numeric = clean[2..4].chars().all(char::is_numeric);
-                first_2_alpha && next_2_numeric
-            }
-            _ => false,
         }
+        Ok(())
     }
-}
 
-impl Normalize for LCCN {
-    /// Normalize an LCCN string based on the
-    /// [Library of Congress criteria](https://www.loc.gov/marc/lccn-namespace.html#syntax)
+    /// The label in front of the LCCN, such as "LCCN" or "LC control no."
     ///
-    /// If the LCCN content is valid, it will return it in a `Some`
+    /// ```
+    /// use library_stdnums::{IdentifierKind, Normalize, LCCN};
+    /// let lccn = LCCN::new("LC control no.: n78-890351");
+    /// assert_eq!(lccn.label().unwrap().kind, IdentifierKind::LCCN);
+    /// assert_eq!(lccn.normalize().unwrap(), "n78890351");
+    /// ```
+    pub fn label(&self) -> Option<Label> {
+        label::parse(&unicode::clean(&self.identifier))
+            .map(|(label, _)| label)
+            .filter(|label| label.kind == IdentifierKind::LCCN)
+    }
+
+    // The identifier with Unicode digits, dashes and spaces folded to ASCII, and any LCCN label removed
+    fn cleaned(&self) -> Cow<'_, str> {
+        label::strip_cleaned(IdentifierKind::LCCN, unicode::clean(&self.identifier))
+    }
+
+    // Valid LCCNs compare by their normalized form, anything else by the raw identifier
+    fn comparison_key(&self) -> String {
+        self.normalize().unwrap_or_else(|| self.identifier.clone())
+    }
+}
+
+impl PartialEq for LCCN {
+    /// Two LCCNs are equal if they normalize to the same value
     ///
     /// ```
     /// use library_stdnums::LCCN;
-    /// use library_stdnums::Normalize;
-    /// assert_eq!(LCCN::new("n78-890351").normalize(), Some("n78890351".to_string()));
-    /// assert_eq!(LCCN::new("n78-890351").normalize().unwrap(), "n78890351");
+    /// assert_eq!(LCCN::new("n78-890351"), LCCN::new("n 78890351 "));
+    /// assert_ne!(LCCN::new("n78-890351"), LCCN::new("nb78-890351"));
     /// ```
-    ///
-    /// Returns None if the LCCN content is not valid
+    fn eq(&self, other: &Self) -> bool {
+        self.comparison_key() == other.comparison_key()
+    }
+}
+
+impl Eq for LCCN {}
+
+impl Hash for LCCN {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.comparison_key().hash(state);
+    }
+}
+
+impl fmt::Display for LCCN {
+    /// Displays the identifier as it was given
     ///
     /// ```
     /// use library_stdnums::LCCN;
-    /// use library_stdnums::Normalize;
-    /// assert!(LCCN::new("Bad LCCN").normalize().is_none());
+    /// assert_eq!(LCCN::new("n78-890351").to_string(), "n78-890351");
     /// ```
-    fn normalize(&self) -> Option<String> {
-        let normalized_version = normalized_version(&self.identifier);
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.identifier)
+    }
+}
+
+impl FromStr for LCCN {
+    type Err = ParseError;
 
-        if LCCN::new(&normalized_version).valid() {
-            return Some(normalized_version);
+    /// Parses a valid LCCN
+    ///
+    /// ```
+    /// use library_stdnums::LCCN;
+    /// assert!("n78-890351".parse::<LCCN>().is_ok());
+    /// assert!("Bad LCCN".parse::<LCCN>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lccn = LCCN::new(s);
+        if lccn.valid() {
+            Ok(lccn)
+        } else {
+            Err(ParseError::InvalidLCCN(s.to_string()))
         }
-        None
     }
 }
 
-fn normalized_version(lccn: &str) -> String {
-    let basic_version = reduce_to_basic(lccn);
-    if !basic_version.contains('-'){
-        return basic_version;
+impl TryFrom<&str> for LCCN {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for LCCN {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if LCCN::new(value.as_str()).valid() {
+            Ok(LCCN::new(value))
+        } else {
+            Err(ParseError::InvalidLCCN(value))
+        }
     }
-    let mut lccn_segments = basic_version.split('-');
-    let first_segment = lccn_segments.next().unwrap_or_default();
-    let second_segment = lccn_segments.next().unwrap_or_default();
-    format!("{}{:0>6}", first_segment, second_segment)
 }
 
-fn reduce_to_basic(lccn: &str) -> String {
-    lccn.replace(char::is_whitespace, "")
-        .replace("http://lccn.loc.gov/", "")
-        .chars()
-        .take_while(|&ch| ch != '/')
-        .collect::<String>()
+// Prefixes in the Library of Congress list of LCCN prefixes, in sorted order
+const PREFIXES: &[&str] = &[
+    "a", "af", "agr", "c", "ca", "cad", "cd", "cs", "cx", "cy", "e", "ee", "f", "fi", "gm", "gs",
+    "h", "ha", "hew", "j", "l", "m", "mp", "mu", "n", "nb", "nc", "nf", "ni", "nn", "no", "nr",
+    "ns", "nt", "nuc", "nx", "r", "rc", "sa", "sc", "sd", "sf", "sh", "sj", "sn", "sp", "sv", "sx",
+    "w", "ws", "x", "xca",
+];
+
+// The current year in UTC
+#[cfg(feature = "std")]
+fn current_year() -> u16 {
+    let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    // Days to civil year, from Howard Hinnant's chrono-compatible algorithms
+    let days = seconds / 86_400 + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let march_based_month = (5 * day_of_year + 2) / 153;
+    let year = year_of_era + era * 400 + u64::from(march_based_month >= 10);
+    u16::try_from(year).unwrap_or(u16::MAX)
+}
+
+/// The components of an LCCN: an optional alphabetic prefix,
+/// a two or four digit year and a six digit serial number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LCCNParts {
+    pub prefix: String,
+    pub year: String,
+    pub serial: String,
+}
+
+impl Identifier for LCCN {
+    type Error = ParseError;
+    type Parts = LCCNParts;
+    const KIND: IdentifierKind = IdentifierKind::LCCN;
+
+    fn parse(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+
+    /// Normalize an LCCN string based on the
+    /// [Library of Congress criteria](https://www.loc.gov/marc/lccn-namespace.html#syntax)
+    ///
+    /// Returns None if the LCCN content is not valid
+    ///
+    /// ```
+    /// use library_stdnums::{Identifier, LCCN};
+    /// assert_eq!(LCCN::new("n78-890351").canonical(), Some("n78890351".to_string()));
+    /// assert!(LCCN::new("Bad LCCN").canonical().is_none());
+    /// ```
+    fn canonical(&self) -> Option<String> {
+        validate::lccn_normalize(self.cleaned().as_bytes()).map(|lccn| lccn.to_string())
+    }
+
+    /// LCCNs are displayed in their normalized form
+    fn display(&self) -> Option<String> {
+        self.canonical()
+    }
+
+    /// LCCNs have no check digit, so this always returns None
+    fn check_digit(&self) -> Option<char> {
+        None
+    }
+
+    /// ```
+    /// use library_stdnums::{Identifier, LCCN};
+    /// let parts = LCCN::new("n78-890351").parts().unwrap();
+    /// assert_eq!(parts.prefix, "n");
+    /// assert_eq!(parts.year, "78");
+    /// assert_eq!(parts.serial, "890351");
+    /// ```
+    fn parts(&self) -> Option<LCCNParts> {
+        let canonical = self.canonical()?;
+        let (head, serial) = canonical.split_at_checked(canonical.len() - 6)?;
+        let year_length = head.chars().rev().take_while(char::is_ascii_digit).count();
+        let (prefix, year) = head.split_at_checked(head.len() - year_length)?;
+        Some(LCCNParts {
+            prefix: prefix.to_string(),
+            year: year.to_string(),
+            serial: serial.to_string(),
+        })
+    }
+
+    /// The permalink of the LCCN
+    ///
+    /// ```
+    /// use library_stdnums::{Identifier, LCCN};
+    /// assert_eq!(LCCN::new("n78-890351").uri().unwrap(), "https://lccn.loc.gov/n78890351");
+    /// ```
+    fn uri(&self) -> Option<String> {
+        Some(format!("https://lccn.loc.gov/{}", self.canonical()?))
+    }
+
+    /// Whether the LCCN has the structure the Library of Congress criteria allow
+    ///
+    /// ```
+    /// use library_stdnums::{Identifier, LCCN};
+    /// assert!(LCCN::new("n78-890351").is_valid());
+    /// assert!(LCCN::new("  2001045944").is_valid());
+    /// assert!(!LCCN::new("Bad LCCN").is_valid());
+    /// ```
+    fn is_valid(&self) -> bool {
+        validate::lccn_valid(self.cleaned().as_bytes())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "std")]
+    use std::collections::HashSet;
 
     #[test]
     fn it_validates_correctly() {
@@ -136,6 +337,10 @@ mod tests {
             !LCCN::new("122001-890351").valid(),
             "122001-890351 should start with two letters"
         );
+        assert!(
+            !LCCN::new("1a2001-890351").valid(),
+            "1a2001-890351 should start with two letters"
+        );
         assert!(
             !LCCN::new("n078-890351").valid(),
             "n078-890351 should start with two letters or two digits"
@@ -163,25 +368,6 @@ mod tests {
     }
 
 
-    #[test]
-    fn it_reduces_to_basic_form() {
-        assert_eq!(
-            reduce_to_basic("n  78890351 "),
-            "n78890351",
-            "It removes spaces"
-        );
-        assert_eq!(
-            reduce_to_basic("http://lccn.loc.gov/89001234"),
-            "89001234",
-            "It removes the URI"
-        );
-        assert_eq!(
-            reduce_to_basic("   94014580 /AC/r95"),
-            "94014580",
-            "It removes everything after the first /"
-        );
-    }
-
     #[test]
     fn it_normalizes() {
         assert_eq!(
@@ -201,6 +387,13 @@ mod tests {
         );
         assert!(LCCN::new("n78-89035100444").normalize().is_none());
     }
+
+    #[test]
+    fn it_strips_labels() {
+        assert_eq!(LCCN::new("LCCN n78-890351").normalize().unwrap(), "n78890351");
+        assert_eq!(LCCN::new("Library of Congress Control Number: 2001-000002").normalize().unwrap(), "2001000002");
+        assert!(LCCN::new("ISSN 85-2").normalize().is_none());
+    }
     #[test]
     fn it_normalizes_all_the_ancient_perl_examples() {
         let test_examples = [["n78-890351", "n78890351"],
@@ -280,4 +473,83 @@ mod tests {
             "It removes suffixes which are not officially part of the lccn"
         );
     }
+
+    #[test]
+    fn it_compares_on_the_normalized_form() {
+        assert_eq!(LCCN::new("85-2"), LCCN::new("   85000002 "));
+        assert_eq!(LCCN::new("http://lccn.loc.gov/89001234"), LCCN::new("89-1234"));
+        assert_ne!(LCCN::new("n78-890351"), LCCN::new("nb78-890351"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn it_hashes_on_the_normalized_form() {
+        let mut lccns = HashSet::new();
+        lccns.insert(LCCN::new("2001-000002"));
+        lccns.insert(LCCN::new("2001000002"));
+        lccns.insert(LCCN::new("gm 71-2450"));
+        assert_eq!(lccns.len(), 2);
+    }
+
+    #[test]
+    fn it_parses() {
+        assert_eq!("n78-890351".parse::<LCCN>().unwrap().identifier, "n78-890351");
+        assert_eq!("n78".parse::<LCCN>(), Err(ParseError::InvalidLCCN("n78".to_string())));
+        assert_eq!(LCCN::try_from(String::from("85-2")).unwrap(), LCCN::new("85000002"));
+    }
+
+    #[test]
+    fn it_implements_identifier() {
+        let lccn = LCCN::parse("nb2001-890351").unwrap();
+        assert_eq!(lccn.kind(), IdentifierKind::LCCN);
+        assert_eq!(lccn.canonical().unwrap(), "nb2001890351");
+        assert_eq!(lccn.display().unwrap(), "nb2001890351");
+        assert_eq!(lccn.check_digit(), None);
+        assert_eq!(lccn.uri().unwrap(), "https://lccn.loc.gov/nb2001890351");
+        assert_eq!(lccn.parts().unwrap(), LCCNParts {
+            prefix: "nb".to_string(),
+            year: "2001".to_string(),
+            serial: "890351".to_string(),
+        });
+        assert_eq!(LCCN::new("85-2").parts().unwrap().prefix, "");
+        assert!(LCCN::parse("n78").is_err());
+    }
+
+    #[test]
+    fn it_round_trips_the_marc_010_form() {
+        for lccn in ["85-2", "n78-890351", "agr25-1", "sn2003-1234", "2001-627090"] {
+            let field = LCCN::new(lccn).marc_010().unwrap();
+            assert_eq!(field.len(), 12);
+            assert_eq!(LCCN::from_marc_010(&field).unwrap(), LCCN::new(lccn));
+        }
+        assert!(LCCN::from_marc_010("n 2001890351").is_ok());
+        assert!(LCCN::from_marc_010("N  78890351 ").is_err(), "Prefixes are lowercase");
+        assert!(LCCN::from_marc_010("n  7889035a ").is_err());
+        assert!(LCCN::from_marc_010("").is_err());
+    }
+
+    #[test]
+    fn it_validates_strictly() {
+        assert_eq!(LCCN::new("n78-890351").validate_strict_as_of(2024), Ok(()));
+        assert_eq!(LCCN::new("85-2").validate_strict_as_of(2024), Ok(()));
+        assert_eq!(LCCN::new("agr25-1").validate_strict_as_of(2024), Ok(()));
+        assert_eq!(LCCN::new("2024-1").validate_strict_as_of(2024), Ok(()));
+        assert_eq!(LCCN::new("2025-1").validate_strict_as_of(2024), Err(LCCNRuleError::ImplausibleYear("2025".to_string())));
+        assert_eq!(LCCN::new("2000-1").validate_strict_as_of(2024), Err(LCCNRuleError::ImplausibleYear("2000".to_string())));
+        assert_eq!(LCCN::new("zzz85-2").validate_strict_as_of(2024), Err(LCCNRuleError::UnknownPrefix("zzz".to_string())));
+        assert_eq!(LCCN::new("n78").validate_strict_as_of(2024), Err(LCCNRuleError::Invalid("n78".to_string())));
+    }
+
+    #[test]
+    fn it_knows_the_current_year() {
+        #[cfg(feature = "std")]
+        assert!(current_year() >= 2024);
+        assert!(PREFIXES.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn it_cleans_unicode_input() {
+        assert_eq!(LCCN::new("n\u{a0}78\u{2011}890351").normalize().unwrap(), "n78890351");
+        assert!(LCCN::new("\u{200b}٨٥\u{2010}٢").valid());
+    }
 }
@@ -1,10 +1,67 @@
+//! Validation and normalization of standard bibliographic numbers
+//!
+//! The check digit and validation core in [`validate`], the [`FixedStr`] buffers
+//! it returns and the compact types build under `#![no_std]` without an allocator.
+//! The identifier types, which own a `String`, need the `alloc` feature, which is
+//! enabled by the default `std` feature.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod batch;
+mod buffer;
+mod compact;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "alloc")]
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "alloc")]
 mod isbn;
+#[cfg(feature = "alloc")]
+mod isbn_ranges;
+#[cfg(feature = "alloc")]
 mod issn;
+pub mod label;
+#[cfg(feature = "alloc")]
 mod lccn;
+#[cfg(feature = "alloc")]
+mod macros;
+#[cfg(feature = "marc")]
+pub mod marc;
+#[cfg(feature = "alloc")]
+mod options;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "alloc")]
+mod standard_number;
 mod traits;
+#[cfg(feature = "alloc")]
+pub mod unicode;
+pub mod validate;
+#[cfg(feature = "wasm-bindgen")]
+mod wasm;
 
-pub use isbn::ISBN;
-pub use issn::ISSN;
-pub use lccn::LCCN;
-pub use traits::Normalize;
+pub use buffer::FixedStr;
+pub use compact::{CompactISBN, CompactISSN, CompactLCCN};
+#[cfg(feature = "alloc")]
+pub use error::{ConversionError, LCCNRuleError, ParseError};
+#[cfg(feature = "alloc")]
+pub use isbn::{ISBNParts, ISBN};
+#[cfg(feature = "alloc")]
+pub use issn::{ISSNParts, ISSN};
+#[cfg(feature = "alloc")]
+pub use lccn::{LCCNParts, LCCN};
+#[cfg(feature = "alloc")]
+pub use options::ParseOptions;
+#[cfg(feature = "alloc")]
+pub use standard_number::{Candidate, StandardNumber};
+#[cfg(feature = "alloc")]
+pub use traits::{Identifier, Normalize};
+pub use traits::IdentifierKind;
 pub use traits::Valid;
@@ -0,0 +1,98 @@
+//! Identifier literals checked at compile time
+//!
+//! The literals are checked with the [`validate`](crate::validate) functions,
+//! which only read bare numbers. A literal with a label such as "ISBN-13:", or
+//! with characters outside ASCII, fails to compile even where the runtime
+//! types would accept it.
+
+/// Creates an [`ISBN`](crate::ISBN) from a string literal, failing to compile if it is invalid
+///
+/// ```
+/// use library_stdnums::{isbn, Normalize};
+/// let isbn = isbn!("978-0-306-40615-7");
+/// assert_eq!(isbn.normalize().unwrap(), "9780306406157");
+/// ```
+///
+/// ```compile_fail
+/// let isbn = library_stdnums::isbn!("978-0-306-40615-2");
+/// ```
+///
+/// The literal must be a bare number, without a label or full-width digits
+///
+/// ```compile_fail
+/// let isbn = library_stdnums::isbn!("ISBN-13: 978-0-306-40615-7");
+/// ```
+///
+/// ```compile_fail
+/// let isbn = library_stdnums::isbn!("９７８０３０６４０６１５７");
+/// ```
+#[macro_export]
+macro_rules! isbn {
+    ($isbn:literal) => {{
+        const _: () = assert!($crate::validate::isbn_valid($isbn.as_bytes()), concat!("invalid ISBN: ", $isbn));
+        $crate::ISBN::new($isbn)
+    }};
+}
+
+/// Creates an [`ISSN`](crate::ISSN) from a string literal, failing to compile if it is invalid
+///
+/// ```
+/// use library_stdnums::{issn, Normalize};
+/// let issn = issn!("1043-383X");
+/// assert_eq!(issn.normalize().unwrap(), "1043383X");
+/// ```
+///
+/// ```compile_fail
+/// let issn = library_stdnums::issn!("0193-451X");
+/// ```
+///
+/// The literal must be a bare number, without a label
+///
+/// ```compile_fail
+/// let issn = library_stdnums::issn!("ISSN 0378-5955");
+/// ```
+#[macro_export]
+macro_rules! issn {
+    ($issn:literal) => {{
+        const _: () = assert!($crate::validate::issn_valid($issn.as_bytes()), concat!("invalid ISSN: ", $issn));
+        $crate::ISSN::new($issn)
+    }};
+}
+
+/// Creates an [`LCCN`](crate::LCCN) from a string literal, failing to compile if it is invalid
+///
+/// ```
+/// use library_stdnums::{lccn, Normalize};
+/// let lccn = lccn!("n78-890351");
+/// assert_eq!(lccn.normalize().unwrap(), "n78890351");
+/// ```
+///
+/// ```compile_fail
+/// let lccn = library_stdnums::lccn!("n78-89035100444");
+/// ```
+///
+/// The literal must be a bare number, without a label
+///
+/// ```compile_fail
+/// let lccn = library_stdnums::lccn!("LCCN n78-890351");
+/// ```
+#[macro_export]
+macro_rules! lccn {
+    ($lccn:literal) => {{
+        const _: () = assert!($crate::validate::lccn_valid($lccn.as_bytes()), concat!("invalid LCCN: ", $lccn));
+        $crate::LCCN::new($lccn)
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Identifier, IdentifierKind, ISBN, ISSN, LCCN};
+
+    #[test]
+    fn it_expands_to_typed_values() {
+        assert_eq!(isbn!("0-306-40615-2"), ISBN::new("9780306406157"));
+        assert_eq!(issn!("0378-5955").kind(), IdentifierKind::ISSN);
+        assert_eq!(lccn!("85-2"), LCCN::new("85000002"));
+        assert_eq!(issn!("1043-383x").identifier, ISSN::new("1043-383x").identifier);
+    }
+}
@@ -0,0 +1,583 @@
+//! Extraction of standard numbers from MARC 21 records
+//!
+//! [`Reader`] streams records from ISO 2709 (binary MARC) files, and, with the
+//! `marcxml` feature, [`xml::Reader`] streams records from MARCXML. Each
+//! [`Record`] lists the standard numbers found in its identifier fields, with
+//! the tag and subfield they came from:
+//!
+//! * 010 $a $z: LCCN
+//! * 020 $a $z: ISBN
+//! * 022 $a $l $y $z: ISSN
+//! * 024 $a $z: ISBN for EANs (first indicator 3) beginning 978 or 979, or the
+//!   type named in $2 (first indicator 7)
+//! * 035 $a $z: LCCN for system control numbers from DLC
+//! * 776 $z: ISBN, 776 $x: ISSN
+//! * 490 $x, 830 $x: ISSN
+//!
+//! ```
+//! use library_stdnums::marc::{Field, Record, Subfield};
+//! use library_stdnums::{Valid, StandardNumber, ISBN};
+//!
+//! let record = Record {
+//!     leader: "00000nam a2200000 a 4500".to_string(),
+//!     fields: vec![
+//!         Field::control("001", "12345"),
+//!         Field::data("020", [' ', ' '], vec![Subfield::new('a', "0306406152 (pbk.)")]),
+//!     ],
+//! };
+//! let identifiers = record.identifiers();
+//! assert_eq!(identifiers[0].number, StandardNumber::ISBN(ISBN::new("9780306406157")));
+//! assert_eq!((identifiers[0].tag.as_str(), identifiers[0].code), ("020", 'a'));
+//! assert!(identifiers[0].number.valid());
+//! ```
+//!
+//! Field data is decoded as UTF-8 when leader/09 is `a`. Otherwise the record
+//! is in MARC-8, which is not decoded: each byte is read as the `char` with the
+//! same value, and written back as that byte, so that records round trip.
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use crate::standard_number::StandardNumber;
+use crate::traits::IdentifierKind;
+
+mod cleanup;
+#[cfg(feature = "marcxml")]
+pub mod xml;
+
+pub use cleanup::Change;
+
+const FIELD_TERMINATOR: u8 = 0x1e;
+const RECORD_TERMINATOR: u8 = 0x1d;
+const SUBFIELD_DELIMITER: u8 = 0x1f;
+
+/// A MARC 21 record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub leader: String,
+    pub fields: Vec<Field>,
+}
+
+/// A control field (tags 001 to 009) or a data field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Field {
+    Control {
+        tag: String,
+        value: String,
+    },
+    Data {
+        tag: String,
+        indicators: [char; 2],
+        subfields: Vec<Subfield>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Subfield {
+    pub code: char,
+    pub value: String,
+}
+
+/// A standard number found in a record
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldIdentifier {
+    /// The identifier, which may be invalid; $z subfields hold invalid numbers by definition
+    pub number: StandardNumber,
+    pub tag: String,
+    pub code: char,
+}
+
+/// The error returned when a record cannot be read
+#[derive(Debug)]
+pub enum MarcError {
+    Io(io::Error),
+    /// The record does not have the structure of a MARC record
+    Malformed(String),
+    /// The record can't be written in ISO 2709 format, because it is too long
+    /// or has characters its encoding can't hold
+    Unwritable(String),
+    #[cfg(feature = "marcxml")]
+    Xml(quick_xml::Error),
+}
+
+impl fmt::Display for MarcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarcError::Io(error) => error.fmt(f),
+            MarcError::Malformed(reason) => write!(f, "malformed MARC record: {}", reason),
+            MarcError::Unwritable(reason) => write!(f, "can't write MARC record: {}", reason),
+            #[cfg(feature = "marcxml")]
+            MarcError::Xml(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for MarcError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MarcError::Io(error) => Some(error),
+            MarcError::Malformed(_) | MarcError::Unwritable(_) => None,
+            #[cfg(feature = "marcxml")]
+            MarcError::Xml(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for MarcError {
+    fn from(error: io::Error) -> Self {
+        MarcError::Io(error)
+    }
+}
+
+impl Field {
+    pub fn control(tag: &str, value: &str) -> Field {
+        Field::Control { tag: tag.to_string(), value: value.to_string() }
+    }
+
+    pub fn data(tag: &str, indicators: [char; 2], subfields: Vec<Subfield>) -> Field {
+        Field::Data { tag: tag.to_string(), indicators, subfields }
+    }
+
+    pub fn tag(&self) -> &str {
+        match self {
+            Field::Control { tag, .. } | Field::Data { tag, .. } => tag,
+        }
+    }
+}
+
+impl Subfield {
+    pub fn new(code: char, value: &str) -> Subfield {
+        Subfield { code, value: value.to_string() }
+    }
+}
+
+impl Record {
+    /// Parses a record in ISO 2709 format, including its record terminator
+    pub fn from_iso2709(data: &[u8]) -> Result<Record, MarcError> {
+        let leader = data
+            .get(..24)
+            .ok_or_else(|| MarcError::Malformed("the leader is shorter than 24 bytes".to_string()))?;
+        let base_address = number(&leader[12..17])?;
+        let directory = data
+            .get(24..base_address.saturating_sub(1))
+            .ok_or_else(|| MarcError::Malformed("the base address is outside the record".to_string()))?;
+        let unicode = is_unicode(leader);
+        let mut fields = Vec::new();
+        for entry in directory.chunks(12) {
+            if entry.len() != 12 {
+                return Err(MarcError::Malformed("incomplete directory entry".to_string()));
+            }
+            let tag = latin1(&entry[..3]);
+            let start = base_address + number(&entry[7..12])?;
+            let content = data
+                .get(start..start + number(&entry[3..7])?)
+                .ok_or_else(|| MarcError::Malformed(format!("field {} is outside the record", tag)))?;
+            let content = content.strip_suffix(&[FIELD_TERMINATOR]).unwrap_or(content);
+            fields.push(if tag.starts_with("00") {
+                Field::Control { value: decode(&tag, content, unicode)?, tag }
+            } else {
+                data_field(tag, content, unicode)?
+            });
+        }
+        Ok(Record { leader: latin1(leader), fields })
+    }
+
+    /// Serializes the record in ISO 2709 format
+    ///
+    /// The record length and base address in the leader are recalculated. Fails
+    /// if a field is longer than 9,999 bytes or the record longer than 99,999,
+    /// or if a MARC-8 record has a character above U+00FF.
+    pub fn to_iso2709(&self) -> Result<Vec<u8>, MarcError> {
+        let unicode = is_unicode(self.leader.as_bytes());
+        let mut directory = Vec::new();
+        let mut data = Vec::new();
+        for field in &self.fields {
+            let start = data.len();
+            match field {
+                Field::Control { tag, value } => encode(tag, value, unicode, &mut data)?,
+                Field::Data { tag, indicators, subfields } => {
+                    data.extend(indicators.map(byte));
+                    for subfield in subfields {
+                        data.push(SUBFIELD_DELIMITER);
+                        encode(tag, subfield.code.encode_utf8(&mut [0; 4]), unicode, &mut data)?;
+                        encode(tag, &subfield.value, unicode, &mut data)?;
+                    }
+                }
+            }
+            data.push(FIELD_TERMINATOR);
+            let mut tag = [b' '; 3];
+            for (slot, c) in tag.iter_mut().zip(field.tag().chars()) {
+                *slot = byte(c);
+            }
+            directory.extend_from_slice(&tag);
+            directory.extend_from_slice(&digits(data.len() - start, 4, field.tag())?);
+            directory.extend_from_slice(&digits(start, 5, field.tag())?);
+        }
+        directory.push(FIELD_TERMINATOR);
+        data.push(RECORD_TERMINATOR);
+
+        let mut leader: Vec<u8> = self.leader.chars().map(byte).collect();
+        leader.resize(24, b' ');
+        let base_address = 24 + directory.len();
+        leader[..5].copy_from_slice(&digits(base_address + data.len(), 5, "the record")?);
+        leader[12..17].copy_from_slice(&digits(base_address, 5, "the directory")?);
+        let mut record = leader;
+        record.extend(directory);
+        record.extend(data);
+        Ok(record)
+    }
+
+    /// The control number in field 001
+    pub fn control_number(&self) -> Option<&str> {
+        self.fields.iter().find_map(|field| match field {
+            Field::Control { tag, value } if tag == "001" => Some(value.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Every standard number in the record's identifier fields, in field order
+    pub fn identifiers(&self) -> Vec<FieldIdentifier> {
+        let mut identifiers = Vec::new();
+        for field in &self.fields {
+            let Field::Data { tag, indicators, subfields } = field else {
+                continue;
+            };
+            for subfield in subfields {
+                if let Some((kind, value)) = identifier_in(tag, indicators, subfields, subfield) {
+                    identifiers.push(FieldIdentifier {
+                        number: StandardNumber::new(kind, value.trim()),
+                        tag: tag.clone(),
+                        code: subfield.code,
+                    });
+                }
+            }
+        }
+        identifiers
+    }
+}
+
+/// Streams records from an ISO 2709 file
+///
+/// ```
+/// use library_stdnums::marc::Reader;
+///
+/// let file: &[u8] = b"00071nam a2200049   4500001000600000020001500006\x1e12345\x1e  \x1fa0306406152\x1e\x1d";
+/// let record = Reader::new(file).next().unwrap().unwrap();
+/// assert_eq!(record.control_number(), Some("12345"));
+/// assert_eq!(record.identifiers().len(), 1);
+/// ```
+pub struct Reader<R> {
+    inner: R,
+}
+
+impl<R: Read> Reader<R> {
+    pub fn new(inner: R) -> Self {
+        Reader { inner }
+    }
+
+    fn read_record(&mut self) -> Result<Option<Record>, MarcError> {
+        let mut length = [0; 5];
+        // Some files separate records with line breaks
+        loop {
+            if self.inner.read(&mut length[..1])? == 0 {
+                return Ok(None);
+            }
+            if !matches!(length[0], b'\n' | b'\r') {
+                break;
+            }
+        }
+        self.inner.read_exact(&mut length[1..])?;
+        let record_length = number(&length)?;
+        if record_length < 24 {
+            return Err(MarcError::Malformed("the record is shorter than its leader".to_string()));
+        }
+        let mut data = vec![0; record_length];
+        data[..5].copy_from_slice(&length);
+        self.inner.read_exact(&mut data[5..])?;
+        if data.last() != Some(&RECORD_TERMINATOR) {
+            return Err(MarcError::Malformed("missing record terminator".to_string()));
+        }
+        Record::from_iso2709(&data).map(Some)
+    }
+}
+
+impl<R: Read> Iterator for Reader<R> {
+    type Item = Result<Record, MarcError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+/// Writes records in ISO 2709 format
+pub struct Writer<W> {
+    inner: W,
+}
+
+impl<W: Write> Writer<W> {
+    pub fn new(inner: W) -> Self {
+        Writer { inner }
+    }
+
+    pub fn write(&mut self, record: &Record) -> Result<(), MarcError> {
+        Ok(self.inner.write_all(&record.to_iso2709()?)?)
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+// Tags and indicators are single bytes, read as Latin-1 so that any byte round trips
+fn latin1(bytes: &[u8]) -> String {
+    bytes.iter().map(|&byte| char::from(byte)).collect()
+}
+
+fn byte(c: char) -> u8 {
+    u8::try_from(c).unwrap_or(b' ')
+}
+
+// Whether leader/09 says the record is in UTF-8 rather than MARC-8
+fn is_unicode(leader: &[u8]) -> bool {
+    leader.get(9) == Some(&b'a')
+}
+
+fn decode(tag: &str, content: &[u8], unicode: bool) -> Result<String, MarcError> {
+    if !unicode {
+        return Ok(latin1(content));
+    }
+    String::from_utf8(content.to_vec())
+        .map_err(|_| MarcError::Malformed(format!("field {} is not UTF-8, as leader/09 says", tag)))
+}
+
+fn encode(tag: &str, text: &str, unicode: bool, data: &mut Vec<u8>) -> Result<(), MarcError> {
+    if unicode {
+        data.extend_from_slice(text.as_bytes());
+        return Ok(());
+    }
+    for c in text.chars() {
+        let byte = u8::try_from(c)
+            .map_err(|_| MarcError::Unwritable(format!("field {} has {:?}, which MARC-8 records can't hold", tag, c)))?;
+        data.push(byte);
+    }
+    Ok(())
+}
+
+// A length or offset as the fixed number of ASCII digits ISO 2709 gives it
+fn digits(value: usize, width: usize, what: &str) -> Result<Vec<u8>, MarcError> {
+    let digits = format!("{:0width$}", value, width = width);
+    if digits.len() > width {
+        return Err(MarcError::Unwritable(format!("{} is too long, at {} bytes", what, value)));
+    }
+    Ok(digits.into_bytes())
+}
+
+fn data_field(tag: String, content: &[u8], unicode: bool) -> Result<Field, MarcError> {
+    let indicator = |index: usize| content.get(index).map_or(' ', |&byte| char::from(byte));
+    let mut subfields = Vec::new();
+    for subfield in content.get(2..).unwrap_or_default().split(|&byte| byte == SUBFIELD_DELIMITER).skip(1) {
+        let value = decode(&tag, subfield, unicode)?;
+        let mut chars = value.chars();
+        if let Some(code) = chars.next() {
+            subfields.push(Subfield { code, value: chars.as_str().to_string() });
+        }
+    }
+    Ok(Field::Data { tag, indicators: [indicator(0), indicator(1)], subfields })
+}
+
+// The kind and text of the standard number in a subfield, if it holds one
+fn identifier_in<'a>(
+    tag: &str,
+    indicators: &[char; 2],
+    subfields: &[Subfield],
+    subfield: &'a Subfield,
+) -> Option<(IdentifierKind, &'a str)> {
+    let value = subfield.value.as_str();
+    let kind = match (tag, subfield.code) {
+        ("010", 'a' | 'z') => IdentifierKind::LCCN,
+        ("020", 'a' | 'z') => IdentifierKind::ISBN,
+        ("022", 'a' | 'l' | 'y' | 'z') => IdentifierKind::ISSN,
+        ("024", 'a' | 'z') => return standard_identifier(indicators[0], subfields).map(|kind| (kind, value)),
+        ("035", 'a' | 'z') => return value.strip_prefix("(DLC)").map(|lccn| (IdentifierKind::LCCN, lccn)),
+        ("776", 'z') => IdentifierKind::ISBN,
+        ("776", 'x') | ("490", 'x') | ("830", 'x') => IdentifierKind::ISSN,
+        _ => return None,
+    };
+    Some((kind, value))
+}
+
+// The kind of number in an 024 field, from its first indicator or source
+fn standard_identifier(first_indicator: char, subfields: &[Subfield]) -> Option<IdentifierKind> {
+    match first_indicator {
+        '3' => {
+            let ean = subfields.iter().find(|subfield| matches!(subfield.code, 'a' | 'z'))?;
+            let ean = ean.value.trim_start();
+            (ean.starts_with("978") || ean.starts_with("979")).then_some(IdentifierKind::ISBN)
+        }
+        '7' => {
+            let source = subfields.iter().find(|subfield| subfield.code == '2')?;
+            match source.value.trim().to_ascii_lowercase().as_str() {
+                "isbn" => Some(IdentifierKind::ISBN),
+                "issn" => Some(IdentifierKind::ISSN),
+                "lccn" => Some(IdentifierKind::LCCN),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn number(digits: &[u8]) -> Result<usize, MarcError> {
+    std::str::from_utf8(digits)
+        .ok()
+        .and_then(|digits| digits.parse().ok())
+        .ok_or_else(|| MarcError::Malformed(format!("{:?} is not a number", String::from_utf8_lossy(digits))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ISBN, ISSN, LCCN};
+
+    // Builds an ISO 2709 record from tags and field contents
+    fn iso2709(fields: &[(&str, &str)]) -> Vec<u8> {
+        let mut directory = String::new();
+        let mut data = String::new();
+        for (tag, content) in fields {
+            let content = format!("{}\x1e", content);
+            directory.push_str(&format!("{}{:04}{:05}", tag, content.len(), data.len()));
+            data.push_str(&content);
+        }
+        let base_address = 24 + directory.len() + 1;
+        let length = base_address + data.len() + 1;
+        format!("{:05}nam a22{:05}   4500{}\x1e{}\x1d", length, base_address, directory, data).into_bytes()
+    }
+
+    fn kinds(record: &Record) -> Vec<(IdentifierKind, String, char)> {
+        record
+            .identifiers()
+            .into_iter()
+            .map(|found| (found.number.kind(), found.tag, found.code))
+            .collect()
+    }
+
+    #[test]
+    fn it_reads_iso2709_records() {
+        let mut file = iso2709(&[("001", "ocm1"), ("010", "  \x1fa   85000002 "), ("245", "10\x1faTitle")]);
+        file.push(b'\n');
+        file.extend(iso2709(&[("001", "ocm2")]));
+        let records: Vec<Record> = Reader::new(file.as_slice()).collect::<Result<_, _>>().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].control_number(), Some("ocm1"));
+        assert_eq!(records[0].fields[2], Field::data("245", ['1', '0'], vec![Subfield::new('a', "Title")]));
+        assert_eq!(records[0].identifiers()[0].number, StandardNumber::LCCN(LCCN::new("85000002")));
+        assert_eq!(records[1].control_number(), Some("ocm2"));
+    }
+
+    #[test]
+    fn it_rejects_truncated_records() {
+        let file = iso2709(&[("001", "ocm1")]);
+        assert!(Reader::new(&file[..30]).next().unwrap().is_err());
+        assert!(matches!(Record::from_iso2709(b"00010"), Err(MarcError::Malformed(_))));
+    }
+
+    #[test]
+    fn it_finds_identifiers_in_every_identifier_field() {
+        let file = iso2709(&[
+            ("010", "  \x1fan  78890351 \x1fzsn 78000123"),
+            ("020", "  \x1fa0306406152\x1fz0306406151\x1fqpbk."),
+            ("022", "0 \x1fa0378-5955\x1fl0378-5955\x1fy0378-5951"),
+            ("024", "3 \x1fa9780306406157"),
+            ("024", "3 \x1fa0012345678905"),
+            ("024", "7 \x1fa1043-383X\x1f2issn"),
+            ("035", "  \x1fa(DLC)   85000002\x1fa(OCoLC)12345"),
+            ("490", "1 \x1faSeries\x1fx0193-4511"),
+            ("776", "08\x1fz9798531132178\x1fx1561-4670"),
+            ("830", " 0\x1faSeries\x1fx0193-4511"),
+        ]);
+        let record = Record::from_iso2709(&file).unwrap();
+        assert_eq!(
+            kinds(&record),
+            vec![
+                (IdentifierKind::LCCN, "010".to_string(), 'a'),
+                (IdentifierKind::LCCN, "010".to_string(), 'z'),
+                (IdentifierKind::ISBN, "020".to_string(), 'a'),
+                (IdentifierKind::ISBN, "020".to_string(), 'z'),
+                (IdentifierKind::ISSN, "022".to_string(), 'a'),
+                (IdentifierKind::ISSN, "022".to_string(), 'l'),
+                (IdentifierKind::ISSN, "022".to_string(), 'y'),
+                (IdentifierKind::ISBN, "024".to_string(), 'a'),
+                (IdentifierKind::ISSN, "024".to_string(), 'a'),
+                (IdentifierKind::LCCN, "035".to_string(), 'a'),
+                (IdentifierKind::ISSN, "490".to_string(), 'x'),
+                (IdentifierKind::ISBN, "776".to_string(), 'z'),
+                (IdentifierKind::ISSN, "776".to_string(), 'x'),
+                (IdentifierKind::ISSN, "830".to_string(), 'x'),
+            ]
+        );
+        let identifiers = record.identifiers();
+        assert_eq!(identifiers[9].number, StandardNumber::LCCN(LCCN::new("85000002")));
+        assert_eq!(identifiers[11].number, StandardNumber::ISBN(ISBN::new("9798531132178")));
+        assert_eq!(identifiers[12].number, StandardNumber::ISSN(ISSN::new("1561-4670")));
+    }
+
+    #[test]
+    fn it_writes_iso2709_records() {
+        let file = iso2709(&[("001", "ocm1"), ("020", "  \x1fa0306406152\x1fqpbk."), ("245", "10\x1faTitlé")]);
+        let record = Record::from_iso2709(&file).unwrap();
+        assert_eq!(record.to_iso2709().unwrap(), file);
+        let mut writer = Writer::new(Vec::new());
+        writer.write(&record).unwrap();
+        writer.write(&record).unwrap();
+        let records: Vec<Record> = Reader::new(writer.into_inner().as_slice()).collect::<Result<_, _>>().unwrap();
+        assert_eq!(records, vec![record.clone(), record]);
+    }
+
+    #[test]
+    fn it_writes_tags_and_indicators_as_single_bytes() {
+        let mut file = iso2709(&[("02~", "~ \x1fa0306406152")]);
+        for byte in file.iter_mut().filter(|byte| **byte == b'~') {
+            *byte = 0xff;
+        }
+        let record = Record::from_iso2709(&file).unwrap();
+        assert_eq!(record.fields, vec![Field::data("02\u{ff}", ['\u{ff}', ' '], vec![Subfield::new('a', "0306406152")])]);
+        assert_eq!(record.to_iso2709().unwrap(), file);
+    }
+
+    #[test]
+    fn it_round_trips_marc8_field_data() {
+        let mut file = iso2709(&[("020", "  \x1fa0306406152\x1fqpbk."), ("245", "10\x1faThe ~Ecole")]);
+        // Leader/09 blank: MARC-8, where 0xe2 is a combining acute accent
+        file[9] = b' ';
+        let accent = file.iter().position(|&byte| byte == b'~').unwrap();
+        file[accent] = 0xe2;
+        let mut record = Record::from_iso2709(&file).unwrap();
+        assert_eq!(record.fields[1], Field::data("245", ['1', '0'], vec![Subfield::new('a', "The \u{e2}Ecole")]));
+        assert_eq!(record.clean_identifiers().len(), 1);
+        let written = record.to_iso2709().unwrap();
+        assert_eq!(written[9], b' ');
+        assert!(written.windows(6).any(|window| window == b"\xe2Ecole"));
+        assert_eq!(Record::from_iso2709(&written).unwrap().fields, record.fields);
+    }
+
+    #[test]
+    fn it_rejects_field_data_that_is_not_utf8() {
+        let mut file = iso2709(&[("245", "10\x1faThe ~Ecole")]);
+        let accent = file.iter().position(|&byte| byte == b'~').unwrap();
+        file[accent] = 0xe2;
+        assert!(matches!(Record::from_iso2709(&file), Err(MarcError::Malformed(_))));
+    }
+
+    #[test]
+    fn it_refuses_to_write_overlong_fields_and_records() {
+        let leader = "00000nam a2200000 a 4500".to_string();
+        let long = "x".repeat(10_000);
+        let record = Record { leader: leader.clone(), fields: vec![Field::control("001", &long)] };
+        assert!(matches!(record.to_iso2709(), Err(MarcError::Unwritable(_))));
+        let field = Field::data("500", [' ', ' '], vec![Subfield::new('a', &long[..9_000])]);
+        let record = Record { leader: leader.clone(), fields: vec![field; 12] };
+        assert!(matches!(record.to_iso2709(), Err(MarcError::Unwritable(_))));
+        let record = Record { leader: leader.replace(" a22", "  22"), fields: vec![Field::control("001", "\u{2603}")] };
+        assert!(matches!(record.to_iso2709(), Err(MarcError::Unwritable(_))));
+    }
+}
@@ -0,0 +1,190 @@
+//! Rewriting of identifier fields into their standard forms
+use crate::label;
+use crate::traits::{Identifier, IdentifierKind};
+use crate::{ISBN, ISSN, LCCN};
+
+use super::{Field, Record, Subfield};
+
+/// A subfield rewritten by [`Record::clean_identifiers`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub tag: String,
+    pub before: Subfield,
+    /// The subfields that replaced it
+    pub after: Vec<Subfield>,
+}
+
+impl Record {
+    /// Rewrites the $a subfields of the 010, 020 and 022 fields into their standard forms
+    ///
+    /// * 010 $a: valid LCCNs take the fixed-width MARC form; invalid ones move to $z
+    /// * 020 $a: valid ISBNs become ISBN-13s and invalid ones move to $z, with any
+    ///   qualifier such as "(pbk.)" moved to $q
+    /// * 022 $a: valid ISSNs are hyphenated; invalid ones move to $y, for incorrect
+    ///   ISSNs. Whether an ISSN was canceled, for $z, can't be told from the number.
+    ///
+    /// Returns the changes made.
+    ///
+    /// ```
+    /// use library_stdnums::marc::{Field, Record, Subfield};
+    ///
+    /// let mut record = Record {
+    ///     leader: "00000nam a2200000 a 4500".to_string(),
+    ///     fields: vec![
+    ///         Field::data("010", [' ', ' '], vec![Subfield::new('a', "n78-890351")]),
+    ///         Field::data("020", [' ', ' '], vec![Subfield::new('a', "0306406152 (pbk.)")]),
+    ///         Field::data("020", [' ', ' '], vec![Subfield::new('a', "0306406151")]),
+    ///     ],
+    /// };
+    /// assert_eq!(record.clean_identifiers().len(), 3);
+    /// assert_eq!(record.fields, vec![
+    ///     Field::data("010", [' ', ' '], vec![Subfield::new('a', "n  78890351 ")]),
+    ///     Field::data("020", [' ', ' '], vec![Subfield::new('a', "9780306406157"), Subfield::new('q', "pbk.")]),
+    ///     Field::data("020", [' ', ' '], vec![Subfield::new('z', "0306406151")]),
+    /// ]);
+    /// ```
+    pub fn clean_identifiers(&mut self) -> Vec<Change> {
+        let mut changes = Vec::new();
+        for field in &mut self.fields {
+            let Field::Data { tag, subfields, .. } = field else {
+                continue;
+            };
+            let rewrite: fn(&str) -> Vec<Subfield> = match tag.as_str() {
+                "010" => lccn,
+                "020" => isbn,
+                "022" => issn,
+                _ => continue,
+            };
+            let mut index = 0;
+            while index < subfields.len() {
+                if subfields[index].code != 'a' {
+                    index += 1;
+                    continue;
+                }
+                let before = subfields[index].clone();
+                let after = rewrite(&before.value);
+                let start = index;
+                index += after.len();
+                if after != [before.clone()] {
+                    subfields.splice(start..=start, after.iter().cloned());
+                    changes.push(Change { tag: tag.clone(), before, after });
+                }
+            }
+        }
+        changes
+    }
+}
+
+fn lccn(value: &str) -> Vec<Subfield> {
+    match LCCN::new(value).marc_010() {
+        Some(structure) => vec![Subfield { code: 'a', value: structure }],
+        None => vec![Subfield::new('z', value)],
+    }
+}
+
+fn isbn(value: &str) -> Vec<Subfield> {
+    // ISBD punctuation, such as the " :" before a price in $c, ends the subfield
+    let trimmed = value.trim().trim_end_matches(|c: char| c.is_whitespace() || ":;/=+".contains(c));
+    let (before, parenthesized) = match trimmed.split_once('(') {
+        Some((before, parenthesized)) => (before, parenthesized.replacen(')', " ", 1)),
+        None => (trimmed, String::new()),
+    };
+    // The number runs up to the first character that can't be part of it, as in "0306406152 pbk."
+    let unlabeled = label::strip(IdentifierKind::ISBN, before);
+    let end = unlabeled
+        .find(|c: char| !(c.is_ascii_digit() || c == 'X' || c == 'x' || c == '-' || c.is_whitespace()))
+        .unwrap_or(unlabeled.len());
+    let (number, rest) = unlabeled.split_at(end);
+    let number = number.trim();
+    // Without a number there is nothing to split the qualifier from
+    if number.is_empty() {
+        return vec![Subfield::new('z', value)];
+    }
+    // 020 $q qualifies the $z of an invalid number as well as the $a of a valid one
+    let compact: String = number.split_whitespace().collect();
+    let mut subfields = match ISBN::new(compact).canonical() {
+        Some(isbn13) => vec![Subfield { code: 'a', value: isbn13 }],
+        None => vec![Subfield::new('z', number)],
+    };
+    let qualifier = [rest.trim(), parenthesized.trim()].join(" ");
+    let qualifier = qualifier.trim();
+    if !qualifier.is_empty() {
+        subfields.push(Subfield::new('q', qualifier));
+    }
+    subfields
+}
+
+fn issn(value: &str) -> Vec<Subfield> {
+    match ISSN::new(value.trim()).display() {
+        Some(hyphenated) => vec![Subfield { code: 'a', value: hyphenated }],
+        None => vec![Subfield::new('y', value)],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cleaned(tag: &str, subfields: Vec<Subfield>) -> (Vec<Subfield>, usize) {
+        let mut record = Record { leader: String::new(), fields: vec![Field::data(tag, [' ', ' '], subfields)] };
+        let changes = record.clean_identifiers().len();
+        match record.fields.remove(0) {
+            Field::Data { subfields, .. } => (subfields, changes),
+            Field::Control { .. } => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn it_cleans_issns() {
+        let (subfields, changes) = cleaned(
+            "022",
+            vec![Subfield::new('a', "1043383x"), Subfield::new('a', "0378-5951"), Subfield::new('2', "1")],
+        );
+        assert_eq!(subfields, vec![Subfield::new('a', "1043-383X"), Subfield::new('y', "0378-5951"), Subfield::new('2', "1")]);
+        assert_eq!(changes, 2);
+    }
+
+    #[test]
+    fn it_cleans_isbns() {
+        let isbn13 = || Subfield::new('a', "9780306406157");
+        assert_eq!(cleaned("020", vec![Subfield::new('a', "ISBN 0306406152")]).0, vec![isbn13()]);
+        assert_eq!(cleaned("020", vec![Subfield::new('a', "978 0 306 40615 7")]).0, vec![isbn13()]);
+        assert_eq!(cleaned("020", vec![Subfield::new('a', "0306406152 :")]).0, vec![isbn13()]);
+        assert_eq!(
+            cleaned("020", vec![Subfield::new('a', "0306406152(pbk.)")]).0,
+            vec![isbn13(), Subfield::new('q', "pbk.")]
+        );
+        assert_eq!(
+            cleaned("020", vec![Subfield::new('a', "0306406152 (pbk.) :")]).0,
+            vec![isbn13(), Subfield::new('q', "pbk.")]
+        );
+        assert_eq!(
+            cleaned("020", vec![Subfield::new('a', "0306406152 pbk.")]).0,
+            vec![isbn13(), Subfield::new('q', "pbk.")]
+        );
+        assert_eq!(
+            cleaned("020", vec![Subfield::new('a', "0306406151 (pbk.)")]).0,
+            vec![Subfield::new('z', "0306406151"), Subfield::new('q', "pbk.")]
+        );
+        assert_eq!(cleaned("020", vec![Subfield::new('a', "0306406151")]).0, vec![Subfield::new('z', "0306406151")]);
+        assert_eq!(cleaned("020", vec![Subfield::new('a', "(pbk.)")]).0, vec![Subfield::new('z', "(pbk.)")]);
+        assert_eq!(cleaned("020", vec![Subfield::new('a', "bad")]).0, vec![Subfield::new('z', "bad")]);
+    }
+
+    #[test]
+    fn it_leaves_clean_fields_alone() {
+        let subfields = vec![Subfield::new('a', "9780306406157"), Subfield::new('q', "pbk."), Subfield::new('z', "123")];
+        assert_eq!(cleaned("020", subfields.clone()), (subfields, 0));
+        let subfields = vec![Subfield::new('a', "  2001000002")];
+        assert_eq!(cleaned("010", subfields.clone()), (subfields, 0));
+    }
+
+    #[test]
+    fn it_cleans_lccns() {
+        let (subfields, _) = cleaned("010", vec![Subfield::new('a', "85-2"), Subfield::new('a', "2001-2"), Subfield::new('a', "bad")]);
+        assert_eq!(
+            subfields,
+            vec![Subfield::new('a', "   85000002 "), Subfield::new('a', "  2001000002"), Subfield::new('z', "bad")]
+        );
+    }
+}
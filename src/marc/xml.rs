@@ -0,0 +1,186 @@
+//! Streaming MARCXML reader
+//!
+//! ```
+//! use library_stdnums::marc::xml::Reader;
+//!
+//! let xml = r#"<collection xmlns="http://www.loc.gov/MARC21/slim">
+//!   <record>
+//!     <leader>00000nam a2200000 a 4500</leader>
+//!     <controlfield tag="001">12345</controlfield>
+//!     <datafield tag="022" ind1="0" ind2=" "><subfield code="a">0378-5955</subfield></datafield>
+//!   </record>
+//! </collection>"#;
+//! let record = Reader::new(xml.as_bytes()).next().unwrap().unwrap();
+//! assert_eq!(record.control_number(), Some("12345"));
+//! assert_eq!(record.identifiers()[0].tag, "022");
+//! ```
+use std::io::BufRead;
+
+use quick_xml::events::{BytesStart, Event};
+
+use super::{Field, MarcError, Record, Subfield};
+
+impl From<quick_xml::Error> for MarcError {
+    fn from(error: quick_xml::Error) -> Self {
+        MarcError::Xml(error)
+    }
+}
+
+/// Streams records from a MARCXML document, one `record` element at a time
+pub struct Reader<R> {
+    xml: quick_xml::Reader<R>,
+    buffer: Vec<u8>,
+}
+
+// The record being read, and the element whose text is being collected
+#[derive(Default)]
+struct Builder {
+    record: Option<Record>,
+    text: Text,
+    value: String,
+}
+
+#[derive(Default)]
+enum Text {
+    #[default]
+    None,
+    Leader,
+    Control(String),
+    Subfield(char),
+}
+
+impl<R: BufRead> Reader<R> {
+    pub fn new(inner: R) -> Self {
+        Reader { xml: quick_xml::Reader::from_reader(inner), buffer: Vec::new() }
+    }
+
+    fn read_record(&mut self) -> Result<Option<Record>, MarcError> {
+        let mut builder = Builder::default();
+        loop {
+            self.buffer.clear();
+            let finished = match self.xml.read_event_into(&mut self.buffer)? {
+                Event::Start(element) => {
+                    builder.start(&element)?;
+                    None
+                }
+                Event::Empty(element) => {
+                    builder.start(&element)?;
+                    builder.end(element.local_name().as_ref())
+                }
+                Event::End(element) => builder.end(element.local_name().as_ref()),
+                Event::Text(content) => {
+                    builder.value.push_str(&content.unescape()?);
+                    None
+                }
+                Event::CData(content) => {
+                    builder.value.push_str(&content.decode().map_err(quick_xml::Error::from)?);
+                    None
+                }
+                Event::Eof if builder.record.is_some() => {
+                    return Err(MarcError::Malformed("the document ends inside a record".to_string()))
+                }
+                Event::Eof => return Ok(None),
+                _ => None,
+            };
+            if finished.is_some() {
+                return Ok(finished);
+            }
+        }
+    }
+}
+
+impl Builder {
+    fn start(&mut self, element: &BytesStart) -> Result<(), MarcError> {
+        let name = element.local_name();
+        if name.as_ref() == b"record" {
+            self.record = Some(Record { leader: String::new(), fields: Vec::new() });
+            return Ok(());
+        }
+        let Some(record) = &mut self.record else {
+            return Ok(());
+        };
+        self.value.clear();
+        self.text = match name.as_ref() {
+            b"leader" => Text::Leader,
+            b"controlfield" => Text::Control(attribute(element, "tag")?),
+            b"datafield" => {
+                let indicator = |name| attribute(element, name).map(|value| value.chars().next().unwrap_or(' '));
+                let indicators = [indicator("ind1")?, indicator("ind2")?];
+                record.fields.push(Field::data(&attribute(element, "tag")?, indicators, Vec::new()));
+                Text::None
+            }
+            b"subfield" => Text::Subfield(attribute(element, "code")?.chars().next().unwrap_or(' ')),
+            _ => Text::None,
+        };
+        Ok(())
+    }
+
+    // Returns the record once its end tag is reached
+    fn end(&mut self, name: &[u8]) -> Option<Record> {
+        if name == b"record" {
+            return self.record.take();
+        }
+        let record = self.record.as_mut()?;
+        match std::mem::take(&mut self.text) {
+            Text::Leader => record.leader = self.value.clone(),
+            Text::Control(tag) => record.fields.push(Field::control(&tag, &self.value)),
+            Text::Subfield(code) => {
+                if let Some(Field::Data { subfields, .. }) = record.fields.last_mut() {
+                    subfields.push(Subfield::new(code, &self.value));
+                }
+            }
+            Text::None => {}
+        }
+        self.value.clear();
+        None
+    }
+}
+
+impl<R: BufRead> Iterator for Reader<R> {
+    type Item = Result<Record, MarcError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+fn attribute(element: &BytesStart, name: &str) -> Result<String, MarcError> {
+    match element.try_get_attribute(name).map_err(quick_xml::Error::from)? {
+        Some(attribute) => Ok(attribute.unescape_value()?.into_owned()),
+        None => Err(MarcError::Malformed(format!("missing {} attribute", name))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{StandardNumber, LCCN};
+
+    #[test]
+    fn it_reads_namespaced_records() {
+        let xml = r#"<?xml version="1.0"?>
+            <marc:collection xmlns:marc="http://www.loc.gov/MARC21/slim">
+              <marc:record>
+                <marc:leader>00000nam a2200000 a 4500</marc:leader>
+                <marc:controlfield tag="001">one</marc:controlfield>
+                <marc:datafield tag="010" ind1=" " ind2=" "><marc:subfield code="a">   85000002 </marc:subfield></marc:datafield>
+                <marc:datafield tag="245" ind1="1" ind2="0"><marc:subfield code="a">Fish &amp; <![CDATA[chips]]></marc:subfield></marc:datafield>
+              </marc:record>
+              <marc:record><marc:controlfield tag="001">two</marc:controlfield></marc:record>
+            </marc:collection>"#;
+        let records: Vec<Record> = Reader::new(xml.as_bytes()).collect::<Result<_, _>>().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].leader, "00000nam a2200000 a 4500");
+        assert_eq!(records[0].fields[2], Field::data("245", ['1', '0'], vec![Subfield::new('a', "Fish & chips")]));
+        assert_eq!(records[0].identifiers()[0].number, StandardNumber::LCCN(LCCN::new("85000002")));
+        assert_eq!(records[1].control_number(), Some("two"));
+    }
+
+    #[test]
+    fn it_rejects_unfinished_records() {
+        let xml = r#"<record><controlfield tag="001">one</controlfield>"#;
+        assert!(Reader::new(xml.as_bytes()).next().unwrap().is_err());
+        let xml = r#"<record><datafield ind1=" " ind2=" "/></record>"#;
+        assert!(matches!(Reader::new(xml.as_bytes()).next(), Some(Err(MarcError::Malformed(_)))));
+    }
+}
@@ -0,0 +1,207 @@
+//! Shared rules for how much cleanup parsing applies to input
+//!
+//! [`ParseOptions`] has three presets:
+//!
+//! * [`ParseOptions::strict`]: only the undecorated form, such as "9780306406157",
+//!   "1043383X" or "n78890351", with nothing before or after it
+//! * [`ParseOptions::standard`]: also ASCII hyphens and spaces, and a lowercase x,
+//!   such as "978-0-306-40615-7", "1043-383x" or "n 78-890351"
+//! * [`ParseOptions::lenient`]: also labels such as "ISBN:", Unicode dashes and
+//!   spaces, full-width digits, and letters commonly mistaken for digits by OCR,
+//!   such as O for 0 and l for 1
+//!
+//! Each rule can also be turned on or off on its own.
+//!
+//! ```
+//! use library_stdnums::{Identifier, ParseOptions, ISBN};
+//!
+//! assert!(ISBN::parse_with("978-0-306-40615-7", &ParseOptions::strict()).is_err());
+//! assert!(ISBN::parse_with("978-0-306-40615-7", &ParseOptions::standard()).is_ok());
+//! assert!(ISBN::parse_with("ISBN: 978‐O‐306‐40615‐7", &ParseOptions::standard()).is_err());
+//! let isbn = ISBN::parse_with("ISBN: 978‐O‐306‐40615‐7", &ParseOptions::lenient()).unwrap();
+//! assert_eq!(isbn.identifier, "978-0-306-40615-7");
+//! ```
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use crate::traits::IdentifierKind;
+use crate::{label, unicode};
+
+/// Which kinds of decoration parsing accepts and removes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// ASCII hyphens and spaces between the parts of the identifier
+    pub separators: bool,
+    /// A leading label naming the kind of identifier, such as "ISBN:", "eISSN" or "LCCN",
+    /// as [`label::strip`] removes it
+    pub labels: bool,
+    /// Unicode digits, dashes, spaces and invisible characters, cleaned as [`unicode::clean`] does
+    pub unicode: bool,
+    /// A lowercase x as the check digit of an ISBN or ISSN
+    pub lowercase: bool,
+    /// Letters that look like digits: O and o for 0, I and l for 1
+    pub confusables: bool,
+}
+
+impl ParseOptions {
+    /// Accepts only the undecorated form of an identifier
+    ///
+    /// Only digits, an uppercase X check digit and an LCCN's lowercase prefix
+    /// are allowed, and nothing may follow the check digit.
+    pub const fn strict() -> Self {
+        ParseOptions { separators: false, labels: false, unicode: false, lowercase: false, confusables: false }
+    }
+
+    /// Accepts ASCII hyphens and spaces, and a lowercase x
+    pub const fn standard() -> Self {
+        ParseOptions { separators: true, labels: false, unicode: false, lowercase: true, confusables: false }
+    }
+
+    /// Accepts every kind of decoration
+    pub const fn lenient() -> Self {
+        ParseOptions { separators: true, labels: true, unicode: true, lowercase: true, confusables: true }
+    }
+
+    /// Cleans the input for parsing as the given kind, or returns None if it is not allowed
+    ///
+    /// Separators are kept, since LCCNs use hyphens to mark where the serial number starts.
+    ///
+    /// ```
+    /// use library_stdnums::{IdentifierKind, ParseOptions};
+    /// let lenient = ParseOptions::lenient();
+    /// assert_eq!(lenient.clean(IdentifierKind::ISSN, "ISSN 0378－5955").unwrap(), "0378-5955");
+    /// assert_eq!(lenient.clean(IdentifierKind::LCCN, "no 2O01-1").unwrap(), "no2001-1");
+    /// assert_eq!(ParseOptions::standard().clean(IdentifierKind::LCCN, "no 2O01-1"), None);
+    /// ```
+    pub fn clean(&self, kind: IdentifierKind, input: &str) -> Option<String> {
+        // Unicode is folded first, as the identifier types do, so full-width labels are found
+        let folded = if self.unicode { unicode::clean(input) } else { Cow::Borrowed(input) };
+        let mut input = folded.trim_matches(|c: char| self.is_space(c));
+        if self.labels {
+            input = label::strip(kind, input);
+        }
+        let mut cleaned = String::with_capacity(input.len());
+        for c in input.chars() {
+            if self.is_space(c) {
+                if !self.separators {
+                    return None;
+                }
+                continue;
+            }
+            // Lowercase letters may belong to an LCCN prefix
+            let c = match c {
+                'O' if self.confusables => '0',
+                'I' if self.confusables => '1',
+                'o' if self.confusables && !is_lccn_prefix(kind, &cleaned) => '0',
+                'l' if self.confusables && !is_lccn_prefix(kind, &cleaned) => '1',
+                c => c,
+            };
+            if !self.allows(kind, &cleaned, c) {
+                return None;
+            }
+            cleaned.push(c);
+        }
+        Some(cleaned)
+    }
+
+    fn is_space(&self, c: char) -> bool {
+        c == ' ' || (self.unicode && c.is_whitespace())
+    }
+
+    // Whether the character may follow what has been cleaned so far
+    fn allows(&self, kind: IdentifierKind, cleaned: &str, c: char) -> bool {
+        // Parsing would ignore anything after the check digit
+        if cleaned.ends_with(['X', 'x']) {
+            return false;
+        }
+        match c {
+            '0'..='9' => true,
+            '-' => self.separators,
+            'X' => kind != IdentifierKind::LCCN,
+            'x' => kind != IdentifierKind::LCCN && self.lowercase,
+            'a'..='z' => kind == IdentifierKind::LCCN && is_lccn_prefix(kind, cleaned),
+            _ => false,
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::standard()
+    }
+}
+
+// Whether an LCCN is still in its alphabetic prefix
+fn is_lccn_prefix(kind: IdentifierKind, cleaned: &str) -> bool {
+    kind == IdentifierKind::LCCN && cleaned.bytes().all(|byte| byte.is_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::Identifier;
+    use crate::{ParseError, ISBN, ISSN, LCCN};
+    use alloc::string::ToString;
+
+    #[test]
+    fn it_parses_strictly() {
+        let strict = ParseOptions::strict();
+        assert_eq!(ISBN::parse_with("0306406152", &strict).unwrap().identifier, "0306406152");
+        assert_eq!(ISSN::parse_with("1043383x", &strict), Err(ParseError::InvalidISSN("1043383x".to_string())));
+        assert!(LCCN::parse_with("n78890351", &strict).is_ok());
+        assert!(LCCN::parse_with("n78-890351", &strict).is_err());
+        assert!(ISBN::parse_with("ABC0306406152", &strict).is_err(), "The built-in prefix scrubbing does not apply");
+    }
+
+    #[test]
+    fn it_parses_standard_forms() {
+        let standard = ParseOptions::default();
+        assert_eq!(ISSN::parse_with(" 1043-383x ", &standard).unwrap().identifier, "1043-383x");
+        assert_eq!(LCCN::parse_with("n 78-890351", &standard).unwrap().identifier, "n78-890351");
+        assert!(LCCN::parse_with("94014580 /AC/r95", &standard).is_err());
+        assert!(ISBN::parse_with("0306406152 (pbk.)", &standard).is_err());
+    }
+
+    #[test]
+    fn it_parses_leniently() {
+        let lenient = ParseOptions::lenient();
+        assert_eq!(ISBN::parse_with("isbn-13: ９７８−０３０６４０６１５７", &lenient).unwrap().identifier, "978-0306406157");
+        assert_eq!(ISBN::parse_with("O-3O6-4O615-2", &lenient).unwrap().identifier, "0-306-40615-2");
+        assert_eq!(ISSN::parse_with("ISSN\u{a0}0l93-45ll", &lenient).unwrap().identifier, "0193-4511");
+        assert_eq!(LCCN::parse_with("LCCN: n 78-89O351", &lenient).unwrap().identifier, "n78-890351");
+        assert_eq!(LCCN::parse_with("LCCN: no78-89o351", &lenient).unwrap().identifier, "no78-890351");
+    }
+
+    #[test]
+    fn it_folds_unicode_before_stripping_labels() {
+        let lenient = ParseOptions::lenient();
+        let isbn = "ＩＳＢＮ：９７８０３０６４０６１５７";
+        assert!(ISBN::new(isbn).is_valid());
+        assert_eq!(ISBN::parse_with(isbn, &lenient).unwrap().identifier, "9780306406157");
+        assert_eq!(ISSN::parse_with("ＩＳＳＮ　０３７８－５９５５", &lenient).unwrap().identifier, "0378-5955");
+        let options = ParseOptions { unicode: false, ..ParseOptions::lenient() };
+        assert!(ISBN::parse_with(isbn, &options).is_err());
+    }
+
+    #[test]
+    fn it_toggles_rules() {
+        let options = ParseOptions { confusables: true, ..ParseOptions::strict() };
+        assert!(ISBN::parse_with("O306406152", &options).is_ok());
+        assert!(ISBN::parse_with("0-306-40615-2", &options).is_err());
+        let options = ParseOptions { lowercase: true, ..ParseOptions::strict() };
+        assert!(ISSN::parse_with("1043383x", &options).is_ok());
+        assert!(ISSN::parse_with("1043-383x", &options).is_err());
+        let options = ParseOptions { lowercase: false, ..ParseOptions::standard() };
+        assert!(ISBN::parse_with("0-8044-2957-x", &options).is_err());
+        assert!(ISBN::parse_with("0-8044-2957-X", &options).is_ok());
+    }
+
+    #[test]
+    fn it_rejects_anything_after_the_check_digit() {
+        assert!(ISBN::parse_with("080442957X", &ParseOptions::strict()).is_ok());
+        assert!(ISBN::parse_with("080442957XX", &ParseOptions::strict()).is_err());
+        assert!(ISBN::parse_with("080442957X1", &ParseOptions::strict()).is_err());
+        assert!(ISBN::parse_with("0-8044-2957-X-", &ParseOptions::standard()).is_err());
+        assert!(ISSN::parse_with("1043383XX", &ParseOptions::lenient()).is_err());
+    }
+}
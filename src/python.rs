@@ -0,0 +1,252 @@
+//! Python bindings, built with [maturin](https://www.maturin.rs) from `pyproject.toml`
+//!
+//! ```python
+//! import library_stdnums
+//!
+//! isbn = library_stdnums.ISBN("0-306-40615-2")
+//! isbn.valid()          # True
+//! isbn.normalize()      # '9780306406157'
+//! library_stdnums.normalize_all("issn", ["0378-5955", "bad"])  # ['03785955', None]
+//! ```
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{batch, Identifier, Normalize, Valid, ISBN, ISSN, LCCN};
+
+/// An International Standard Book Number
+#[pyclass(name = "ISBN", module = "library_stdnums", frozen)]
+struct PyISBN {
+    inner: ISBN,
+}
+
+#[pymethods]
+impl PyISBN {
+    #[new]
+    fn new(identifier: &str) -> Self {
+        PyISBN { inner: ISBN::new(identifier) }
+    }
+
+    #[getter]
+    fn identifier(&self) -> &str {
+        &self.inner.identifier
+    }
+
+    fn valid(&self) -> bool {
+        self.inner.valid()
+    }
+
+    fn normalize(&self) -> Option<String> {
+        self.inner.normalize()
+    }
+
+    fn checkdigit(&self) -> Option<char> {
+        self.inner.checkdigit()
+    }
+
+    fn convert_to_13(&self) -> Option<String> {
+        self.inner.convert_to_13()
+    }
+
+    fn convert_to_10(&self) -> Option<String> {
+        self.inner.convert_to_10().ok()
+    }
+
+    fn hyphenate(&self) -> Option<String> {
+        self.inner.display()
+    }
+
+    fn __str__(&self) -> &str {
+        &self.inner.identifier
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ISBN({:?})", self.inner.identifier)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash(&self.inner)
+    }
+}
+
+/// An International Standard Serial Number
+#[pyclass(name = "ISSN", module = "library_stdnums", frozen)]
+struct PyISSN {
+    inner: ISSN,
+}
+
+#[pymethods]
+impl PyISSN {
+    #[new]
+    fn new(identifier: &str) -> Self {
+        PyISSN { inner: ISSN::new(identifier) }
+    }
+
+    #[getter]
+    fn identifier(&self) -> &str {
+        &self.inner.identifier
+    }
+
+    fn valid(&self) -> bool {
+        self.inner.valid()
+    }
+
+    fn normalize(&self) -> Option<String> {
+        self.inner.normalize()
+    }
+
+    fn checkdigit(&self) -> char {
+        self.inner.checkdigit()
+    }
+
+    fn hyphenate(&self) -> Option<String> {
+        self.inner.display()
+    }
+
+    fn __str__(&self) -> &str {
+        &self.inner.identifier
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ISSN({:?})", self.inner.identifier)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash(&self.inner)
+    }
+}
+
+/// A Library of Congress Control Number
+#[pyclass(name = "LCCN", module = "library_stdnums", frozen)]
+struct PyLCCN {
+    inner: LCCN,
+}
+
+#[pymethods]
+impl PyLCCN {
+    #[new]
+    fn new(identifier: &str) -> Self {
+        PyLCCN { inner: LCCN::new(identifier) }
+    }
+
+    #[getter]
+    fn identifier(&self) -> &str {
+        &self.inner.identifier
+    }
+
+    fn valid(&self) -> bool {
+        self.inner.valid()
+    }
+
+    fn normalize(&self) -> Option<String> {
+        self.inner.normalize()
+    }
+
+    fn marc_010(&self) -> Option<String> {
+        self.inner.marc_010()
+    }
+
+    fn __str__(&self) -> &str {
+        &self.inner.identifier
+    }
+
+    fn __repr__(&self) -> String {
+        format!("LCCN({:?})", self.inner.identifier)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash(&self.inner)
+    }
+}
+
+/// Whether each value is a valid identifier of the kind: "isbn", "issn" or "lccn"
+#[pyfunction]
+fn valid_all(py: Python<'_>, kind: &str, values: Vec<String>) -> PyResult<Vec<bool>> {
+    let validate = match kind.to_ascii_lowercase().as_str() {
+        "isbn" => batch::validate::<ISBN, String>,
+        "issn" => batch::validate::<ISSN, String>,
+        "lccn" => batch::validate::<LCCN, String>,
+        _ => return Err(unknown_kind(kind)),
+    };
+    Ok(py.detach(|| validate(values).results))
+}
+
+/// The normalized form of each value, or None where it is invalid
+#[pyfunction]
+fn normalize_all(py: Python<'_>, kind: &str, values: Vec<String>) -> PyResult<Vec<Option<String>>> {
+    let normalize = match kind.to_ascii_lowercase().as_str() {
+        "isbn" => batch::normalize::<ISBN, String>,
+        "issn" => batch::normalize::<ISSN, String>,
+        "lccn" => batch::normalize::<LCCN, String>,
+        _ => return Err(unknown_kind(kind)),
+    };
+    Ok(py.detach(|| normalize(values).results))
+}
+
+/// The ISBN-13 of each value, or None where it is not a valid ISBN
+#[pyfunction]
+fn convert_all_to_13(py: Python<'_>, values: Vec<String>) -> Vec<Option<String>> {
+    py.detach(|| values.iter().map(|value| ISBN::new(value.as_str()).convert_to_13()).collect())
+}
+
+fn unknown_kind(kind: &str) -> PyErr {
+    PyValueError::new_err(format!("unknown identifier kind {:?}, expected isbn, issn or lccn", kind))
+}
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[pymodule]
+fn library_stdnums(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyISBN>()?;
+    module.add_class::<PyISSN>()?;
+    module.add_class::<PyLCCN>()?;
+    module.add_function(wrap_pyfunction!(valid_all, module)?)?;
+    module.add_function(wrap_pyfunction!(normalize_all, module)?)?;
+    module.add_function(wrap_pyfunction!(convert_all_to_13, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_wraps_the_identifier_types() {
+        let isbn = PyISBN::new("0-306-40615-2");
+        assert!(isbn.valid());
+        assert_eq!(isbn.convert_to_13().unwrap(), "9780306406157");
+        assert_eq!(isbn.__repr__(), "ISBN(\"0-306-40615-2\")");
+        assert_eq!(isbn.__hash__(), PyISBN::new("9780306406157").__hash__());
+        assert_eq!(PyISSN::new("1043-383x").checkdigit(), 'X');
+        assert_eq!(PyLCCN::new("n78-890351").marc_010().unwrap(), "n  78890351 ");
+    }
+
+    #[test]
+    fn it_processes_lists() {
+        Python::initialize();
+        Python::attach(|py| {
+            let values = vec!["0378-5955".to_string(), "bad".to_string()];
+            assert_eq!(normalize_all(py, "ISSN", values.clone()).unwrap(), vec![Some("03785955".to_string()), None]);
+            assert_eq!(valid_all(py, "lccn", values).unwrap(), vec![true, false]);
+            assert!(valid_all(py, "doi", vec![]).is_err());
+        });
+    }
+}
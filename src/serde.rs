@@ -0,0 +1,174 @@
+//! Serde support for the identifier types, enabled with the `serde` feature
+//!
+//! Identifiers serialize as the string they were created from, and deserialize
+//! only if they are valid.
+//!
+//! ```
+//! use library_stdnums::ISBN;
+//! let isbn: ISBN = serde_json::from_str("\"0-306-40615-2\"").unwrap();
+//! assert_eq!(serde_json::to_string(&isbn).unwrap(), "\"0-306-40615-2\"");
+//! assert!(serde_json::from_str::<ISBN>("\"0-306-40615-X\"").is_err());
+//! ```
+use core::fmt::Display;
+use core::str::FromStr;
+
+use alloc::string::String;
+
+use ::serde::de::Error as _;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{ISBN, ISSN, LCCN};
+
+impl Serialize for ISBN {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.identifier)
+    }
+}
+
+impl<'de> Deserialize<'de> for ISBN {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_parsed(deserializer)
+    }
+}
+
+impl Serialize for ISSN {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.identifier)
+    }
+}
+
+impl<'de> Deserialize<'de> for ISSN {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_parsed(deserializer)
+    }
+}
+
+impl Serialize for LCCN {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.identifier)
+    }
+}
+
+impl<'de> Deserialize<'de> for LCCN {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_parsed(deserializer)
+    }
+}
+
+fn deserialize_parsed<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    let identifier = String::deserialize(deserializer)?;
+    identifier.parse().map_err(D::Error::custom)
+}
+
+/// Serialize identifiers in their normalized form
+///
+/// Use with `#[serde(with = "library_stdnums::serde::normalized")]`.
+/// Deserialization accepts any valid form, just like the default implementation.
+///
+/// ```
+/// use library_stdnums::ISBN;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "library_stdnums::serde::normalized")]
+///     isbn: ISBN,
+/// }
+///
+/// let record: Record = serde_json::from_str(r#"{"isbn": "0-306-40615-2"}"#).unwrap();
+/// assert_eq!(serde_json::to_string(&record).unwrap(), r#"{"isbn":"9780306406157"}"#);
+/// ```
+pub mod normalized {
+    use core::fmt::Display;
+    use core::str::FromStr;
+
+    use ::serde::ser::Error as _;
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::Normalize;
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Normalize,
+        S: Serializer,
+    {
+        match value.normalize() {
+            Some(normalized) => serializer.serialize_str(&normalized),
+            None => Err(S::Error::custom("cannot serialize an invalid identifier in normalized form")),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        super::deserialize_parsed(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        isbn: ISBN,
+        issn: ISSN,
+        lccn: LCCN,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct NormalizedRecord {
+        #[serde(with = "normalized")]
+        isbn: ISBN,
+        #[serde(with = "normalized")]
+        issn: ISSN,
+        #[serde(with = "normalized")]
+        lccn: LCCN,
+    }
+
+    #[test]
+    fn it_round_trips_the_original_input() {
+        let json = r#"{"isbn":"0-306-40615-2","issn":"1043-383x","lccn":"n78-890351"}"#;
+        let record: Record = serde_json::from_str(json).unwrap();
+        assert_eq!(record.isbn.identifier, "0-306-40615-2");
+        assert_eq!(serde_json::to_string(&record).unwrap(), json);
+    }
+
+    #[test]
+    fn it_serializes_the_normalized_form() {
+        let json = r#"{"isbn":"0-306-40615-2","issn":"1043-383x","lccn":"n78-890351"}"#;
+        let record: NormalizedRecord = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"isbn":"9780306406157","issn":"1043383X","lccn":"n78890351"}"#
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_identifiers() {
+        let error = serde_json::from_str::<ISBN>(r#""0-306-40615-X""#).unwrap_err();
+        assert!(error.to_string().contains("invalid ISBN"));
+        assert!(serde_json::from_str::<ISSN>(r#""0193-451X""#).is_err());
+        assert!(serde_json::from_str::<LCCN>(r#""n78""#).is_err());
+        assert!(serde_json::from_str::<ISBN>("9780306406157").is_err());
+    }
+
+    #[test]
+    fn it_refuses_to_serialize_invalid_identifiers_in_normalized_form() {
+        let record = NormalizedRecord {
+            isbn: ISBN::new("Bad ISBN"),
+            issn: ISSN::new("0378-5955"),
+            lccn: LCCN::new("n78-890351"),
+        };
+        assert!(serde_json::to_string(&record).is_err());
+    }
+}
@@ -0,0 +1,335 @@
+use core::fmt;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::error::ParseError;
+use crate::traits::{Identifier, IdentifierKind, Normalize, Valid};
+use crate::{label, unicode, validate, ISBN, ISSN, LCCN};
+
+/// Any of the standard numbers supported by this crate
+///
+/// Use [`StandardNumber::detect`] when the type of a raw string is not known
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StandardNumber {
+    ISBN(ISBN),
+    ISSN(ISSN),
+    LCCN(LCCN),
+}
+
+/// A possible interpretation of a raw string, with a confidence score between 0 and 1
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub number: StandardNumber,
+    pub confidence: f32,
+}
+
+impl StandardNumber {
+    /// A standard number of the given kind, valid or not
+    ///
+    /// ```
+    /// use library_stdnums::{IdentifierKind, StandardNumber, ISSN};
+    /// assert_eq!(StandardNumber::new(IdentifierKind::ISSN, "0378-5955"), StandardNumber::ISSN(ISSN::new("03785955")));
+    /// ```
+    pub fn new(kind: IdentifierKind, raw: &str) -> StandardNumber {
+        match kind {
+            IdentifierKind::ISBN => StandardNumber::ISBN(ISBN::new(raw)),
+            IdentifierKind::ISSN => StandardNumber::ISSN(ISSN::new(raw)),
+            IdentifierKind::LCCN => StandardNumber::LCCN(LCCN::new(raw)),
+        }
+    }
+
+    /// Parses a valid standard number of the given kind
+    ///
+    /// ```
+    /// use library_stdnums::{IdentifierKind, ParseError, StandardNumber};
+    /// assert!(StandardNumber::parse(IdentifierKind::LCCN, "n78-890351").is_ok());
+    /// assert_eq!(StandardNumber::parse(IdentifierKind::ISBN, "123"), Err(ParseError::InvalidISBN("123".to_string())));
+    /// ```
+    pub fn parse(kind: IdentifierKind, raw: &str) -> Result<StandardNumber, ParseError> {
+        Ok(match kind {
+            IdentifierKind::ISBN => StandardNumber::ISBN(raw.parse()?),
+            IdentifierKind::ISSN => StandardNumber::ISSN(raw.parse()?),
+            IdentifierKind::LCCN => StandardNumber::LCCN(raw.parse()?),
+        })
+    }
+
+    /// The kind of standard number
+    ///
+    /// ```
+    /// use library_stdnums::{IdentifierKind, StandardNumber, ISSN};
+    /// assert_eq!(StandardNumber::from(ISSN::new("0378-5955")).kind(), IdentifierKind::ISSN);
+    /// ```
+    pub fn kind(&self) -> IdentifierKind {
+        match self {
+            StandardNumber::ISBN(isbn) => isbn.kind(),
+            StandardNumber::ISSN(issn) => issn.kind(),
+            StandardNumber::LCCN(lccn) => lccn.kind(),
+        }
+    }
+
+    /// Returns the most likely valid interpretation of a raw string
+    ///
+    /// ```
+    /// use library_stdnums::{StandardNumber, ISBN, ISSN};
+    /// let best = StandardNumber::detect("978-0-306-40615-7").unwrap();
+    /// assert_eq!(best.number, StandardNumber::ISBN(ISBN::new("9780306406157")));
+    ///
+    /// let best = StandardNumber::detect("0378-5955").unwrap();
+    /// assert_eq!(best.number, StandardNumber::ISSN(ISSN::new("03785955")));
+    /// ```
+    ///
+    /// Returns None if the string is not a valid standard number of any type
+    ///
+    /// ```
+    /// use library_stdnums::StandardNumber;
+    /// assert!(StandardNumber::detect("Bad number").is_none());
+    /// ```
+    pub fn detect(raw: &str) -> Option<Candidate> {
+        StandardNumber::candidates(raw)
+            .into_iter()
+            .find(|candidate| candidate.number.valid())
+    }
+
+    /// Returns every plausible interpretation of a raw string, most likely first
+    ///
+    /// The confidence takes into account the length, check digit validity,
+    /// prefix and hyphenation of the string. Candidates with the right shape but
+    /// an invalid check digit are included with a low confidence.
+    ///
+    /// ```
+    /// use library_stdnums::{StandardNumber, Valid};
+    /// // Both a valid ISSN and a valid LCCN
+    /// let candidates = StandardNumber::candidates("03785955");
+    /// assert_eq!(candidates.len(), 2);
+    /// assert!(matches!(candidates[0].number, StandardNumber::ISSN(_)));
+    /// assert!(matches!(candidates[1].number, StandardNumber::LCCN(_)));
+    /// assert!(candidates.iter().all(|candidate| candidate.number.valid()));
+    /// ```
+    pub fn candidates(raw: &str) -> Vec<Candidate> {
+        let mut candidates = Vec::new();
+        let cleaned = unicode::clean(raw);
+        // A label rules out the other kinds
+        let (labeled, unlabeled) = match label::parse(&cleaned) {
+            Some((label, rest)) => (Some(label.kind), rest),
+            None => (None, &*cleaned),
+        };
+        let allowed = |kind| labeled.is_none_or(|labeled| labeled == kind);
+        let has_label = labeled.is_some();
+        if let Some(confidence) = isbn_confidence(unlabeled, has_label).filter(|_| allowed(IdentifierKind::ISBN)) {
+            candidates.push(Candidate { number: StandardNumber::ISBN(ISBN::new(raw)), confidence });
+        }
+        if let Some(confidence) = issn_confidence(unlabeled, has_label).filter(|_| allowed(IdentifierKind::ISSN)) {
+            candidates.push(Candidate { number: StandardNumber::ISSN(ISSN::new(raw)), confidence });
+        }
+        if let Some(confidence) = lccn_confidence(unlabeled, has_label).filter(|_| allowed(IdentifierKind::LCCN)) {
+            candidates.push(Candidate { number: StandardNumber::LCCN(LCCN::new(raw)), confidence });
+        }
+        candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        candidates
+    }
+}
+
+impl Valid for StandardNumber {
+    fn valid(&self) -> bool {
+        match self {
+            StandardNumber::ISBN(isbn) => isbn.valid(),
+            StandardNumber::ISSN(issn) => issn.valid(),
+            StandardNumber::LCCN(lccn) => lccn.valid(),
+        }
+    }
+}
+
+impl Normalize for StandardNumber {
+    fn normalize(&self) -> Option<String> {
+        match self {
+            StandardNumber::ISBN(isbn) => isbn.normalize(),
+            StandardNumber::ISSN(issn) => issn.normalize(),
+            StandardNumber::LCCN(lccn) => lccn.normalize(),
+        }
+    }
+}
+
+impl fmt::Display for StandardNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StandardNumber::ISBN(isbn) => isbn.fmt(f),
+            StandardNumber::ISSN(issn) => issn.fmt(f),
+            StandardNumber::LCCN(lccn) => lccn.fmt(f),
+        }
+    }
+}
+
+impl From<ISBN> for StandardNumber {
+    fn from(isbn: ISBN) -> Self {
+        StandardNumber::ISBN(isbn)
+    }
+}
+
+impl From<ISSN> for StandardNumber {
+    fn from(issn: ISSN) -> Self {
+        StandardNumber::ISSN(issn)
+    }
+}
+
+impl From<LCCN> for StandardNumber {
+    fn from(lccn: LCCN) -> Self {
+        StandardNumber::LCCN(lccn)
+    }
+}
+
+fn isbn_confidence(raw: &str, has_label: bool) -> Option<f32> {
+    let isbn = ISBN::new(raw);
+    let basic = isbn.reduce_to_basic();
+    let mut confidence = match basic.len() {
+        10 => 0.3,
+        13 if basic.starts_with("978") || basic.starts_with("979") => 0.5,
+        13 => 0.2,
+        _ => return None,
+    };
+    if isbn.valid() {
+        confidence += 0.4;
+    }
+    if has_label {
+        confidence += 0.2;
+    }
+    let groups = raw.trim().split('-').count();
+    if (basic.len() == 10 && groups == 4) || (basic.len() == 13 && groups == 5) {
+        confidence += 0.1;
+    }
+    Some(clamp(confidence))
+}
+
+fn issn_confidence(raw: &str, has_label: bool) -> Option<f32> {
+    let trimmed = raw.trim();
+    let mut confidence = match ISSN::new(trimmed).canonical() {
+        Some(_) => 0.7,
+        None => {
+            // Seven digits and a check digit, which is wrong
+            let basic = trimmed.replace('-', "");
+            let shaped = basic.len() == 8
+                && validate::issn_check_digit(basic.as_bytes()).is_some()
+                && basic.ends_with(|c: char| c.is_ascii_digit() || c == 'X' || c == 'x');
+            if !shaped {
+                return None;
+            }
+            0.3
+        }
+    };
+    if trimmed.len() == 9 && trimmed.find('-') == Some(4) {
+        confidence += 0.2;
+    }
+    if has_label {
+        confidence += 0.2;
+    }
+    Some(clamp(confidence))
+}
+
+fn lccn_confidence(raw: &str, has_label: bool) -> Option<f32> {
+    let lccn = LCCN::new(raw);
+    if !lccn.valid() {
+        return None;
+    }
+    // LCCNs have no check digit, so validity alone is weaker evidence
+    let mut confidence = 0.5;
+    let trimmed = raw.trim();
+    if trimmed.contains("lccn.loc.gov") || has_label {
+        confidence += 0.3;
+    }
+    let without_prefix = trimmed.trim_start_matches(|c: char| c.is_ascii_alphabetic() || c == ' ');
+    if without_prefix.len() < trimmed.len() {
+        confidence += 0.2;
+    }
+    if let Some((year, _serial)) = without_prefix.split_once('-') {
+        // Four digit years were introduced in 2001
+        let plausible_year = year.len() == 2 || (year.len() == 4 && year.starts_with("20"));
+        if plausible_year && year.chars().all(|c| c.is_ascii_digit()) {
+            confidence += 0.2;
+        }
+    }
+    Some(clamp(confidence))
+}
+
+fn clamp(confidence: f32) -> f32 {
+    confidence.min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn kinds(raw: &str) -> Vec<String> {
+        StandardNumber::candidates(raw)
+            .iter()
+            .map(|candidate| candidate.number.kind().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn it_detects_isbns() {
+        assert_eq!(kinds("978-0-306-40615-7"), ["ISBN"]);
+        assert_eq!(kinds("ISBN: 0-8044-2957-X"), ["ISBN"]);
+        let best = StandardNumber::detect("ISBN: 978-0-306-40615-7").unwrap();
+        assert_eq!(best.confidence, 1.0);
+    }
+
+    #[test]
+    fn it_detects_issns() {
+        assert_eq!(kinds("1043-383X"), ["ISSN"]);
+        // Also a valid LCCN, but the hyphenation suggests an ISSN
+        assert_eq!(kinds("0378-5955"), ["ISSN", "LCCN"]);
+    }
+
+    #[test]
+    fn it_detects_lccns() {
+        assert_eq!(kinds("n78-890351"), ["LCCN"]);
+        assert_eq!(kinds("http://lccn.loc.gov/89001234"), ["LCCN"]);
+        assert_eq!(kinds("85-2"), ["LCCN"]);
+    }
+
+    #[test]
+    fn it_only_detects_the_labeled_kind() {
+        assert_eq!(kinds("ISBN-13: 978-0-306-40615-7"), ["ISBN"]);
+        assert_eq!(kinds("eISSN 0378-5955"), ["ISSN"]);
+        assert_eq!(kinds("LCCN 03785955"), ["LCCN"]);
+        assert_eq!(StandardNumber::detect("ISSN 1043-383X (Online)").unwrap().confidence, 1.0);
+    }
+
+    #[test]
+    fn it_reports_every_candidate_for_ambiguous_strings() {
+        assert_eq!(kinds("03785955"), ["ISSN", "LCCN"]);
+        // A valid ISBN10 and a valid LCCN with a four digit year: the check
+        // digit makes the ISBN more likely, and a hyphen after the year makes
+        // the LCCN more likely than it was
+        let candidates = StandardNumber::candidates("2001890354");
+        assert_eq!(kinds("2001890354"), ["ISBN", "LCCN"]);
+        assert!(candidates.iter().all(|candidate| candidate.number.valid()));
+        assert!(candidates[0].confidence > candidates[1].confidence);
+        let hyphenated = StandardNumber::candidates("2001-890354");
+        assert_eq!(hyphenated.len(), 2);
+        assert!(hyphenated.iter().all(|candidate| candidate.number.valid()));
+        let lccn = |candidates: &[Candidate]| {
+            candidates.iter().find(|candidate| candidate.number.kind() == IdentifierKind::LCCN).unwrap().confidence
+        };
+        assert!(lccn(&hyphenated) > lccn(&candidates));
+    }
+
+    #[test]
+    fn it_ranks_invalid_check_digits_below_valid_candidates() {
+        // Not a valid ISSN, but a valid LCCN
+        let candidates = StandardNumber::candidates("85000002");
+        assert_eq!(candidates.len(), 2);
+        assert!(matches!(candidates[0].number, StandardNumber::LCCN(_)));
+        assert!(!candidates[1].number.valid());
+        assert!(matches!(StandardNumber::detect("85000002").unwrap().number, StandardNumber::LCCN(_)));
+    }
+
+    #[test]
+    fn it_detects_nothing_in_garbage() {
+        assert!(StandardNumber::candidates("Bad number").is_empty());
+        assert!(StandardNumber::candidates("019X-4511").is_empty());
+        assert!(StandardNumber::detect("978-0-306-40615-3").is_none());
+        assert_eq!(kinds("978-0-306-40615-3"), ["ISBN"]);
+    }
+}
@@ -1,7 +1,114 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use crate::{error::ParseError, options::ParseOptions};
+
 pub trait Valid {
     fn valid(&self) -> bool;
 }
 
+#[cfg(feature = "alloc")]
 pub trait Normalize: Valid {
     fn normalize(&self) -> Option<String>;
 }
+
+/// The kinds of standard number supported by this crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IdentifierKind {
+    ISBN,
+    ISSN,
+    LCCN,
+}
+
+impl fmt::Display for IdentifierKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdentifierKind::ISBN => f.write_str("ISBN"),
+            IdentifierKind::ISSN => f.write_str("ISSN"),
+            IdentifierKind::LCCN => f.write_str("LCCN"),
+        }
+    }
+}
+
+/// Common behaviour of every standard number
+///
+/// Types implementing `Identifier` get [`Valid`] and [`Normalize`] for free:
+/// an identifier is valid if it has a canonical form, and normalizes to it.
+///
+/// ```
+/// use library_stdnums::{Identifier, IdentifierKind, ISBN, ISSN};
+///
+/// fn describe<T: Identifier>(identifier: &T) -> String {
+///     format!("{} {}", identifier.kind(), identifier.display().unwrap_or_default())
+/// }
+///
+/// assert_eq!(describe(&ISBN::new("0306406152")), "ISBN 978-0-306-40615-7");
+/// assert_eq!(describe(&ISSN::new("03785955")), "ISSN 0378-5955");
+/// ```
+#[cfg(feature = "alloc")]
+pub trait Identifier: Sized {
+    /// The error returned when parsing fails
+    type Error;
+    /// The structural components of the identifier
+    type Parts;
+    /// The kind of standard number
+    const KIND: IdentifierKind;
+
+    /// Parses a valid identifier
+    fn parse(input: &str) -> Result<Self, Self::Error>;
+
+    /// Parses a valid identifier, cleaning the input as the options allow
+    ///
+    /// The identifier holds the cleaned input. See [`ParseOptions`].
+    fn parse_with(input: &str, options: &ParseOptions) -> Result<Self, ParseError>
+    where
+        Self: Identifier<Error = ParseError>,
+    {
+        match options.clean(Self::KIND, input) {
+            Some(cleaned) => Self::parse(&cleaned).map_err(|_| ParseError::new(Self::KIND, input)),
+            None => Err(ParseError::new(Self::KIND, input)),
+        }
+    }
+
+    /// The kind of standard number
+    fn kind(&self) -> IdentifierKind {
+        Self::KIND
+    }
+
+    /// The normalized form used for storage and comparison, or None if the identifier is invalid
+    fn canonical(&self) -> Option<String>;
+
+    /// The form intended for people to read, or None if the identifier is invalid
+    fn display(&self) -> Option<String>;
+
+    /// The check digit of a valid identifier, or None if it is invalid or has no check digit
+    fn check_digit(&self) -> Option<char>;
+
+    /// The structural components of a valid identifier
+    fn parts(&self) -> Option<Self::Parts>;
+
+    /// A URI identifying the resource, or None if the identifier is invalid
+    fn uri(&self) -> Option<String>;
+
+    /// Whether the identifier is valid
+    fn is_valid(&self) -> bool {
+        self.canonical().is_some()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Identifier> Valid for T {
+    fn valid(&self) -> bool {
+        self.is_valid()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Identifier> Normalize for T {
+    fn normalize(&self) -> Option<String> {
+        self.canonical()
+    }
+}
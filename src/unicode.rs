@@ -0,0 +1,140 @@
+//! Unicode-aware cleaning of copy-pasted identifiers
+//!
+//! Identifiers copied from web pages and word processors often contain en
+//! dashes, non-breaking hyphens and spaces, zero-width characters, full-width
+//! digits, or digits from other scripts. [`clean`] folds these to the ASCII
+//! characters the identifier types understand:
+//!
+//! * decimal digits of every script (Unicode category Nd) become ASCII digits
+//! * dash punctuation (category Pd) and the minus sign become ASCII hyphens,
+//!   which ISBNs and ISSNs ignore and LCCNs use to mark the serial number
+//! * space separators (category Zs) become ASCII spaces
+//! * invisible formatting characters (category Cf), such as soft hyphens and
+//!   zero-width spaces, are removed
+//! * full-width ASCII forms become ASCII
+//!
+//! `ISBN`, `ISSN` and `LCCN` clean non-ASCII input this way before validating
+//! or normalizing it.
+//!
+//! ```
+//! use library_stdnums::{unicode, Normalize, ISBN};
+//!
+//! assert_eq!(unicode::clean("978\u{2011}0\u{2011}306\u{2011}40615\u{2011}7"), "978-0-306-40615-7");
+//! assert_eq!(unicode::clean("٠٣٠٦٤٠٦١٥٢"), "0306406152");
+//! assert_eq!(ISBN::new("９７８\u{200b}０３０６４０６１５７").normalize().unwrap(), "9780306406157");
+//! ```
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+// The zero of every run of decimal digits in Unicode 15.0, in order
+const DIGIT_ZEROS: &[u32] = &[
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0, 0x11650,
+    0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x11F50, 0x16A60, 0x16AC0, 0x16B50,
+    0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0, 0x1E950, 0x1FBF0,
+];
+
+// Dash punctuation, and the minus sign
+const DASHES: &[char] = &[
+    '\u{058A}', '\u{05BE}', '\u{1400}', '\u{1806}', '\u{2010}', '\u{2011}', '\u{2012}', '\u{2013}',
+    '\u{2014}', '\u{2015}', '\u{2212}', '\u{2E17}', '\u{2E1A}', '\u{2E3A}', '\u{2E3B}', '\u{2E40}',
+    '\u{2E5D}', '\u{301C}', '\u{3030}', '\u{30A0}', '\u{FE31}', '\u{FE32}', '\u{FE58}', '\u{FE63}',
+    '\u{FF0D}', '\u{10EAD}',
+];
+
+// Ranges of invisible formatting characters
+const FORMATTING: &[(u32, u32)] = &[
+    (0x00AD, 0x00AD), (0x0600, 0x0605), (0x061C, 0x061C), (0x06DD, 0x06DD), (0x070F, 0x070F),
+    (0x0890, 0x0891), (0x08E2, 0x08E2), (0x180E, 0x180E), (0x200B, 0x200F), (0x202A, 0x202E),
+    (0x2060, 0x2064), (0x2066, 0x206F), (0xFEFF, 0xFEFF), (0xFFF9, 0xFFFB), (0x110BD, 0x110BD),
+    (0x110CD, 0x110CD), (0x13430, 0x1343F), (0x1BCA0, 0x1BCA3), (0x1D173, 0x1D17A), (0xE0001, 0xE0001),
+    (0xE0020, 0xE007F),
+];
+
+/// Cleans an identifier as described in the [module documentation](self)
+///
+/// ASCII input is returned as it is, without allocating.
+pub fn clean(input: &str) -> Cow<'_, str> {
+    if input.is_ascii() {
+        return Cow::Borrowed(input);
+    }
+    let cleaned: String = input.chars().filter_map(fold).collect();
+    Cow::Owned(cleaned)
+}
+
+/// Folds one character to ASCII where it has an equivalent, or None if it is invisible
+///
+/// ```
+/// use library_stdnums::unicode;
+/// assert_eq!(unicode::fold('\u{2013}'), Some('-'));
+/// assert_eq!(unicode::fold('৭'), Some('7'));
+/// assert_eq!(unicode::fold('\u{00ad}'), None);
+/// assert_eq!(unicode::fold('é'), Some('é'));
+/// ```
+pub fn fold(c: char) -> Option<char> {
+    if c.is_ascii() {
+        return Some(c);
+    }
+    let code = u32::from(c);
+    if let Some(digit) = digit_value(code) {
+        return Some(char::from(b'0' + digit));
+    }
+    if DASHES.contains(&c) {
+        return Some('-');
+    }
+    if c.is_whitespace() {
+        return Some(' ');
+    }
+    if FORMATTING.iter().any(|&(first, last)| (first..=last).contains(&code)) {
+        return None;
+    }
+    // Full-width forms of the printable ASCII characters
+    if (0xFF01..=0xFF5E).contains(&code) {
+        return char::from_u32(code - 0xFF01 + 0x21);
+    }
+    Some(c)
+}
+
+fn digit_value(code: u32) -> Option<u8> {
+    let zero = match DIGIT_ZEROS.binary_search(&code) {
+        Ok(index) => DIGIT_ZEROS[index],
+        Err(0) => return None,
+        Err(index) => DIGIT_ZEROS[index - 1],
+    };
+    u8::try_from(code - zero).ok().filter(|&digit| digit < 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_maps_digits_of_every_script() {
+        assert_eq!(clean("۰۳۷۸-۵۹۵۵"), "0378-5955", "Extended Arabic-Indic");
+        assert_eq!(clean("०३७८-५९५५"), "0378-5955", "Devanagari");
+        assert_eq!(clean("𝟎𝟑𝟕𝟖"), "0378", "Mathematical bold");
+        assert_eq!(fold('\u{0669}'), Some('9'));
+        assert_eq!(fold('\u{066a}'), Some('\u{066a}'), "The Arabic percent sign follows the digits");
+    }
+
+    #[test]
+    fn it_folds_separators_and_removes_formatting() {
+        assert_eq!(clean("n78\u{2010}890351"), "n78-890351");
+        assert_eq!(clean("0378\u{00a0}5955\u{feff}"), "0378 5955");
+        assert_eq!(clean("\u{202a}0378\u{00ad}5955\u{202c}"), "03785955");
+        assert_eq!(clean("ＩＳＢＮ：１０４３３８３Ｘ"), "ISBN:1043383X");
+    }
+
+    #[test]
+    fn it_does_not_allocate_for_ascii() {
+        assert!(matches!(clean("0378-5955"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn it_keeps_the_digit_table_sorted() {
+        assert!(DIGIT_ZEROS.windows(2).all(|pair| pair[1] - pair[0] >= 10));
+        assert!(DIGIT_ZEROS.iter().all(|&zero| char::from_u32(zero + 9).is_some_and(char::is_numeric)));
+    }
+}
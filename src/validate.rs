@@ -0,0 +1,555 @@
+//! Validation and check digit calculation on borrowed input, without allocating
+//!
+//! These functions read ASCII numbers with the rules `ISBN`, `ISSN` and `LCCN`
+//! use internally. Check digits are returned as ASCII bytes, and normalized
+//! identifiers as [`FixedStr`] buffers, so this module is available under
+//! `#![no_std]` without an allocator. Every function is a `const fn`, so
+//! identifiers known at compile time can be checked in constants.
+//!
+//! ```
+//! use library_stdnums::validate;
+//! assert!(validate::isbn_valid(b"978-0-306-40615-7"));
+//! assert!(validate::issn_valid("0378-5955".as_bytes()));
+//! assert!(validate::lccn_valid(b"n78-890351"));
+//! assert_eq!(validate::isbn_check_digit(b"0-8044-2957-X"), Some(b'X'));
+//!
+//! const CHECK_DIGIT: Option<u8> = validate::issn_check_digit(b"1043-383");
+//! assert_eq!(CHECK_DIGIT, Some(b'X'));
+//! ```
+//!
+//! Unlike the types, these functions don't remove labels such as "ISBN-13:",
+//! or fold full-width digits and other Unicode characters to ASCII, so they
+//! reject some input the types accept.
+//!
+//! ```
+//! use library_stdnums::{validate, Valid, ISSN};
+//! assert!(ISSN::new("ISSN 0378-5955").valid());
+//! assert!(!validate::issn_valid(b"ISSN 0378-5955"));
+//! assert!(ISSN::new("０３７８－５９５５").valid());
+//! assert!(!validate::issn_valid("０３７８－５９５５".as_bytes()));
+//! ```
+use crate::buffer::FixedStr;
+#[cfg(test)]
+use alloc::string::String;
+
+/// Calculate the check digit of an ISBN10 or ISBN13
+///
+/// Returns None if the ISBN is not a valid length
+///
+/// ```
+/// use library_stdnums::validate;
+/// assert_eq!(validate::isbn_check_digit(b"0139381430"), Some(b'0'));
+/// assert_eq!(validate::isbn_check_digit(b"978-0-306-40615-2"), Some(b'7'));
+/// assert_eq!(validate::isbn_check_digit(b"Bad ISBN"), None);
+/// ```
+pub const fn isbn_check_digit(isbn: &[u8]) -> Option<u8> {
+    let basic = match isbn_basic(isbn) {
+        Some(basic) => basic,
+        None => return None,
+    };
+    match basic.len() {
+        10 => Some(isbn10_check_digit(basic.as_bytes())),
+        13 => Some(isbn13_check_digit(basic.as_bytes())),
+        _ => None,
+    }
+}
+
+/// Assert if an ISBN10 or ISBN13 is valid by verifying the check digit
+///
+/// ```
+/// use library_stdnums::validate;
+/// assert!(validate::isbn_valid(b"ISBN: 0-8044-2957-X"));
+/// assert!(!validate::isbn_valid(b"0139381432"));
+/// ```
+pub const fn isbn_valid(isbn: &[u8]) -> bool {
+    match isbn_basic(isbn) {
+        Some(basic) => isbn_basic_valid(basic.as_bytes()),
+        None => false,
+    }
+}
+
+/// Calculate the check digit of an ISSN
+///
+/// Returns None unless the first seven characters, ignoring hyphens, are digits
+///
+/// ```
+/// use library_stdnums::validate;
+/// assert_eq!(validate::issn_check_digit(b"1043-383"), Some(b'X'));
+/// assert_eq!(validate::issn_check_digit(b"abcdefg"), None);
+/// ```
+pub const fn issn_check_digit(issn: &[u8]) -> Option<u8> {
+    let mut digits = 0;
+    let mut i = 0;
+    while i < issn.len() && digits < 7 {
+        let byte = issn[i];
+        i += 1;
+        if byte == b'-' {
+            continue;
+        }
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        digits += 1;
+    }
+    if digits == 7 {
+        Some(issn_weighted_check_digit(issn))
+    } else {
+        None
+    }
+}
+
+/// Assert if an ISSN is valid by verifying the check digit
+///
+/// ```
+/// use library_stdnums::validate;
+/// assert!(validate::issn_valid(b"1043-383x"));
+/// assert!(!validate::issn_valid(b"0378-5951"));
+/// ```
+pub const fn issn_valid(issn: &[u8]) -> bool {
+    issn_normalize(issn).is_some()
+}
+
+/// Assert if the content of an LCCN is valid based on
+/// [Library of Congress criteria](https://www.loc.gov/marc/lccn-namespace.html#syntax)
+///
+/// ```
+/// use library_stdnums::validate;
+/// assert!(validate::lccn_valid(b"   94014580 /AC/r95"));
+/// assert!(!validate::lccn_valid(b"n78-89c0351"));
+/// ```
+pub const fn lccn_valid(lccn: &[u8]) -> bool {
+    lccn_normalize(lccn).is_some()
+}
+
+/// Reduce an ISBN to its digits and check digit: hyphens are removed, then
+/// everything before the first digit and after the first run of digits, or the
+/// first X, is dropped
+///
+/// Returns None if the result is too long to be an ISBN
+pub(crate) const fn isbn_basic(isbn: &[u8]) -> Option<FixedStr<13>> {
+    let mut basic = FixedStr::new();
+    let mut started = false;
+    let mut i = 0;
+    while i < isbn.len() {
+        let byte = isbn[i];
+        i += 1;
+        if byte == b'-' {
+            continue;
+        }
+        if !started {
+            if !byte.is_ascii_digit() {
+                continue;
+            }
+            started = true;
+        }
+        if !(byte.is_ascii_digit() || byte == b'X' || byte == b'x') {
+            break;
+        }
+        if !basic.push(byte.to_ascii_uppercase()) {
+            return None;
+        }
+        // Only the check digit can be an X
+        if byte == b'X' || byte == b'x' {
+            break;
+        }
+    }
+    Some(basic)
+}
+
+/// Convert a valid ISBN to its ISBN13, which is also its normalized form
+///
+/// ```
+/// use library_stdnums::validate;
+/// assert_eq!(validate::isbn_to_13(b"0-306-40615-2").unwrap(), "9780306406157");
+/// assert!(validate::isbn_to_13(b"013938143").is_none());
+/// ```
+pub const fn isbn_to_13(isbn: &[u8]) -> Option<FixedStr<13>> {
+    let basic = match isbn_basic(isbn) {
+        Some(basic) => basic,
+        None => return None,
+    };
+    let basic = basic.as_bytes();
+    if !isbn_basic_valid(basic) {
+        return None;
+    }
+    let mut isbn13 = FixedStr::new();
+    if basic.len() == 10 {
+        isbn13.push_all(b"978");
+        isbn13.push_all(basic.split_at(9).0);
+        isbn13.push(isbn13_check_digit(isbn13.as_bytes()));
+    } else {
+        isbn13.push_all(basic);
+    }
+    Some(isbn13)
+}
+
+/// Convert a valid ISBN to its ISBN10, unless it is an ISBN13 beginning with 979
+///
+/// ```
+/// use library_stdnums::validate;
+/// assert_eq!(validate::isbn_to_10(b"9780306406157").unwrap(), "0306406152");
+/// assert!(validate::isbn_to_10(b"9798531132178").is_none());
+/// assert_eq!(validate::isbn_to_10(b"9790000006").unwrap(), "9790000006");
+/// ```
+pub const fn isbn_to_10(isbn: &[u8]) -> Option<FixedStr<10>> {
+    let basic = match isbn_basic(isbn) {
+        Some(basic) => basic,
+        None => return None,
+    };
+    let basic = basic.as_bytes();
+    if !isbn_basic_valid(basic) {
+        return None;
+    }
+    // An ISBN10 in the 979 registration group is returned as it is
+    if basic.len() == 13 && basic[0] == b'9' && basic[1] == b'7' && basic[2] == b'9' {
+        return None;
+    }
+    let mut isbn10 = FixedStr::new();
+    if basic.len() == 13 {
+        let without_prefix = basic.split_at(3).1;
+        isbn10.push_all(without_prefix.split_at(9).0);
+        isbn10.push(isbn10_check_digit(without_prefix));
+    } else {
+        isbn10.push_all(basic);
+    }
+    Some(isbn10)
+}
+
+/// Reduce a valid ISSN to its eight characters, with an uppercase check digit
+///
+/// ```
+/// use library_stdnums::validate;
+/// assert_eq!(validate::issn_normalize(b"1043-383x").unwrap(), "1043383X");
+/// assert!(validate::issn_normalize(b"0378-5951").is_none());
+/// ```
+pub const fn issn_normalize(issn: &[u8]) -> Option<FixedStr<8>> {
+    let mut basic: FixedStr<8> = FixedStr::new();
+    let mut i = 0;
+    while i < issn.len() {
+        let byte = issn[i];
+        i += 1;
+        if byte == b'-' {
+            continue;
+        }
+        let byte = if byte == b'x' { b'X' } else { byte };
+        if !(byte.is_ascii_digit() || byte == b'X') || !basic.push(byte) {
+            return None;
+        }
+    }
+    let basic_bytes = basic.as_bytes();
+    if basic_bytes.len() != 8 {
+        return None;
+    }
+    let mut i = 0;
+    while i < 7 {
+        if basic_bytes[i] == b'X' {
+            return None;
+        }
+        i += 1;
+    }
+    if basic_bytes[7] == issn_weighted_check_digit(basic_bytes) {
+        Some(basic)
+    } else {
+        None
+    }
+}
+
+/// The ISSN check digit, calculated from the digits among the first seven
+/// characters once hyphens are removed
+pub(crate) const fn issn_weighted_check_digit(issn: &[u8]) -> u8 {
+    let mut sum = 0;
+    let mut weight = 8;
+    let mut characters = 0;
+    let mut i = 0;
+    while i < issn.len() && characters < 7 {
+        let byte = issn[i];
+        i += 1;
+        if byte == b'-' {
+            continue;
+        }
+        characters += 1;
+        if byte.is_ascii_digit() {
+            sum += (byte - b'0') as u32 * weight;
+            weight -= 1;
+        }
+    }
+    mod_11_check_digit(sum)
+}
+
+/// Normalize a valid LCCN: whitespace, the lccn.loc.gov URI and anything after
+/// a `/` are removed, and the serial number after a hyphen is padded to six digits
+///
+/// ```
+/// use library_stdnums::validate;
+/// assert_eq!(validate::lccn_normalize(b"n78-890351").unwrap(), "n78890351");
+/// assert!(validate::lccn_normalize(b"n78").is_none());
+/// ```
+pub const fn lccn_normalize(lccn: &[u8]) -> Option<FixedStr<12>> {
+    let mut stripped = LCCNStripped::new(lccn);
+    let mut normalized = FixedStr::new();
+    let mut hyphenated = false;
+    while let Some(byte) = stripped.next_byte() {
+        if byte == b'-' {
+            hyphenated = true;
+            break;
+        }
+        if !normalized.push(byte) {
+            return None;
+        }
+    }
+    if hyphenated {
+        // The serial number runs up to the next hyphen, and is padded to six digits
+        let mut serial_length = 0;
+        let mut lookahead = stripped;
+        while let Some(byte) = lookahead.next_byte() {
+            if byte == b'-' {
+                break;
+            }
+            serial_length += 1;
+        }
+        while serial_length < 6 {
+            if !normalized.push(b'0') {
+                return None;
+            }
+            serial_length += 1;
+        }
+        while let Some(byte) = stripped.next_byte() {
+            if byte == b'-' {
+                break;
+            }
+            if !normalized.push(byte) {
+                return None;
+            }
+        }
+    }
+    if lccn_structure_valid(normalized.as_bytes()) {
+        Some(normalized)
+    } else {
+        None
+    }
+}
+
+const LCCN_URI: &[u8] = b"http://lccn.loc.gov/";
+
+/// The bytes of an LCCN with whitespace and the lccn.loc.gov URI removed,
+/// up to the first `/`
+#[derive(Clone, Copy)]
+pub(crate) struct LCCNStripped<'a> {
+    lccn: &'a [u8],
+    position: usize,
+}
+
+impl<'a> LCCNStripped<'a> {
+    pub(crate) const fn new(lccn: &'a [u8]) -> Self {
+        LCCNStripped { lccn, position: 0 }
+    }
+
+    pub(crate) const fn next_byte(&mut self) -> Option<u8> {
+        while self.position < self.lccn.len() {
+            let byte = self.lccn[self.position];
+            if is_whitespace(byte) {
+                self.position += 1;
+                continue;
+            }
+            if let Some(after_uri) = self.skip_uri(self.position) {
+                self.position = after_uri;
+                continue;
+            }
+            if byte == b'/' {
+                self.position = self.lccn.len();
+                return None;
+            }
+            self.position += 1;
+            return Some(byte);
+        }
+        None
+    }
+
+    // The position after the URI, if it starts at the given position
+    const fn skip_uri(&self, position: usize) -> Option<usize> {
+        let mut position = position;
+        let mut matched = 0;
+        while matched < LCCN_URI.len() {
+            if position >= self.lccn.len() {
+                return None;
+            }
+            let byte = self.lccn[position];
+            position += 1;
+            if is_whitespace(byte) {
+                continue;
+            }
+            if byte != LCCN_URI[matched] {
+                return None;
+            }
+            matched += 1;
+        }
+        Some(position)
+    }
+}
+
+impl Iterator for LCCNStripped<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        self.next_byte()
+    }
+}
+
+const fn lccn_structure_valid(clean: &[u8]) -> bool {
+    let length = clean.len();
+    let mut i = length.saturating_sub(8);
+    while i < length {
+        if !clean[i].is_ascii_digit() {
+            return false;
+        }
+        i += 1;
+    }
+    match length {
+        8 => true,
+        9 => clean[0].is_ascii_alphabetic(),
+        10 => two_digits(clean, 0) || two_letters(clean, 0),
+        11 => clean[0].is_ascii_alphabetic() && (two_digits(clean, 1) || two_letters(clean, 1)),
+        12 => two_letters(clean, 0) && two_digits(clean, 2),
+        _ => false,
+    }
+}
+
+const fn two_digits(clean: &[u8], index: usize) -> bool {
+    clean[index].is_ascii_digit() && clean[index + 1].is_ascii_digit()
+}
+
+const fn two_letters(clean: &[u8], index: usize) -> bool {
+    clean[index].is_ascii_alphabetic() && clean[index + 1].is_ascii_alphabetic()
+}
+
+const fn isbn_basic_valid(basic: &[u8]) -> bool {
+    match basic.len() {
+        10 => isbn10_check_digit(basic) == basic[9],
+        13 => isbn13_check_digit(basic) == basic[12],
+        _ => false,
+    }
+}
+
+// Calculated from the digits among the first nine characters
+const fn isbn10_check_digit(basic: &[u8]) -> u8 {
+    let mut sum = 0;
+    let mut weight = 10;
+    let mut i = 0;
+    while i < basic.len() && i < 9 {
+        if basic[i].is_ascii_digit() {
+            sum += (basic[i] - b'0') as u32 * weight;
+            weight -= 1;
+        }
+        i += 1;
+    }
+    mod_11_check_digit(sum)
+}
+
+// Calculated from the digits among the first twelve characters
+const fn isbn13_check_digit(basic: &[u8]) -> u8 {
+    let mut sum = 0;
+    let mut digits = 0;
+    let mut i = 0;
+    while i < basic.len() && i < 12 {
+        if basic[i].is_ascii_digit() {
+            let weight = if digits % 2 == 0 { 1 } else { 3 };
+            sum += (basic[i] - b'0') as u32 * weight;
+            digits += 1;
+        }
+        i += 1;
+    }
+    b'0' + ((10 - sum % 10) % 10) as u8
+}
+
+const fn mod_11_check_digit(sum: u32) -> u8 {
+    match (11 - sum % 11) % 11 {
+        10 => b'X',
+        digit => b'0' + digit as u8,
+    }
+}
+
+// The ASCII characters matched by char::is_whitespace
+const fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | 0x0b | 0x0c | b'\r')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stripped(lccn: &str) -> String {
+        LCCNStripped::new(lccn.as_bytes()).map(char::from).collect()
+    }
+
+    #[test]
+    fn it_reduces_isbns_to_basic() {
+        assert_eq!(isbn_basic(b"0-8044-2957-X").unwrap().as_str(), "080442957X");
+        assert_eq!(isbn_basic(b"ABC0139381430").unwrap().as_str(), "0139381430");
+        assert_eq!(isbn_basic(b"0139381430 (pbk.)").unwrap().as_str(), "0139381430");
+        assert_eq!(isbn_basic(b"406X0XX2xx").unwrap().as_str(), "406X");
+        assert!(isbn_basic(b"97803064061579780").is_none());
+    }
+
+    #[test]
+    fn it_calculates_isbn_check_digits() {
+        assert_eq!(isbn_check_digit(b"0139381430"), Some(b'0'));
+        assert_eq!(isbn_check_digit(b"0-8044-2957-X"), Some(b'X'));
+        assert_eq!(isbn_check_digit(b"9781449373320"), Some(b'0'));
+        assert_eq!(isbn_check_digit(b"9780306406152"), Some(b'7'));
+        assert_eq!(isbn_check_digit(b""), None);
+    }
+
+    #[test]
+    fn it_converts_isbns() {
+        assert_eq!(isbn_to_13(b"0-306-40615-2").unwrap().as_str(), "9780306406157");
+        assert_eq!(isbn_to_10(b"9780306406157").unwrap().as_str(), "0306406152");
+        assert!(isbn_to_10(b"9798531132178").is_none());
+        assert_eq!(isbn_to_10(b"979-0000-00-6").unwrap().as_str(), "9790000006");
+        assert!(isbn_to_13(b"013938143").is_none());
+    }
+
+    #[test]
+    fn it_validates_issns() {
+        assert!(issn_valid(b"0193-4511"));
+        assert!(issn_valid(b"1043-383x"));
+        assert!(!issn_valid(b"0193-451X"));
+        assert!(!issn_valid(b"019X-4511"));
+        assert!(!issn_valid(b""));
+        assert!(!issn_valid(b"-"));
+        assert!(!issn_valid(b"00000000000"), "ISSNs have eight characters");
+    }
+
+    #[test]
+    fn it_calculates_issn_check_digits() {
+        assert_eq!(issn_check_digit(b"0193-4511"), Some(b'1'));
+        assert_eq!(issn_check_digit(b"1561467"), Some(b'0'));
+        assert_eq!(issn_check_digit(b"156"), None);
+    }
+
+    #[test]
+    fn it_evaluates_in_constants() {
+        const ISBN13: Option<FixedStr<13>> = isbn_to_13(b"0-306-40615-2");
+        const LCCN: Option<FixedStr<12>> = lccn_normalize(b"n78-890351");
+        const VALID: [bool; 3] = [isbn_valid(b"0-8044-2957-X"), issn_valid(b"0378-5955"), lccn_valid(b"85-2")];
+        assert_eq!(ISBN13.unwrap().as_str(), "9780306406157");
+        assert_eq!(LCCN.unwrap().as_str(), "n78890351");
+        assert_eq!(VALID, [true; 3]);
+    }
+
+    #[test]
+    fn it_strips_lccns() {
+        assert_eq!(stripped("n  78890351 "), "n78890351", "It removes spaces");
+        assert_eq!(stripped("http://lccn.loc.gov/89001234"), "89001234", "It removes the URI");
+        assert_eq!(stripped("http://lccn .loc.gov/ 89001234"), "89001234", "It removes the URI after removing spaces");
+        assert_eq!(stripped("   94014580 /AC/r95"), "94014580", "It removes everything after the first /");
+    }
+
+    #[test]
+    fn it_normalizes_lccns() {
+        assert_eq!(lccn_normalize(b"85-2").unwrap().as_str(), "85000002");
+        assert_eq!(lccn_normalize(b"n78-890351-ignored").unwrap().as_str(), "n78890351");
+        assert!(lccn_normalize(b"n78-89035100444").is_none());
+        assert!(lccn_normalize(b"").is_none());
+        assert!(lccn_normalize("é7890351".as_bytes()).is_none());
+    }
+}
@@ -0,0 +1,143 @@
+//! JavaScript bindings, built for the browser with
+//!
+//! ```text
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm-bindgen --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/library_stdnums.wasm
+//! ```
+//!
+//! ```js
+//! import init, { ISBN, ISSN } from "./pkg/library_stdnums.js";
+//!
+//! await init();
+//! new ISBN("0-306-40615-2").convertTo13();  // "9780306406157"
+//! new ISSN("0378-5955").valid();            // true
+//! ```
+//!
+//! Methods that can't produce a value for an invalid identifier return `undefined`.
+use wasm_bindgen::prelude::*;
+
+use crate::{Identifier, Normalize, Valid, ISBN, ISSN, LCCN};
+
+/// An International Standard Book Number
+#[wasm_bindgen(js_name = ISBN)]
+pub struct JsISBN {
+    inner: ISBN,
+}
+
+#[wasm_bindgen(js_class = ISBN)]
+impl JsISBN {
+    #[wasm_bindgen(constructor)]
+    pub fn new(identifier: &str) -> JsISBN {
+        JsISBN { inner: ISBN::new(identifier) }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn identifier(&self) -> String {
+        self.inner.identifier.clone()
+    }
+
+    pub fn valid(&self) -> bool {
+        self.inner.valid()
+    }
+
+    pub fn normalize(&self) -> Option<String> {
+        self.inner.normalize()
+    }
+
+    pub fn checkdigit(&self) -> Option<String> {
+        self.inner.checkdigit().map(String::from)
+    }
+
+    #[wasm_bindgen(js_name = convertTo13)]
+    pub fn convert_to_13(&self) -> Option<String> {
+        self.inner.convert_to_13()
+    }
+
+    #[wasm_bindgen(js_name = convertTo10)]
+    pub fn convert_to_10(&self) -> Option<String> {
+        self.inner.convert_to_10().ok()
+    }
+
+    pub fn hyphenate(&self) -> Option<String> {
+        self.inner.display()
+    }
+}
+
+/// An International Standard Serial Number
+#[wasm_bindgen(js_name = ISSN)]
+pub struct JsISSN {
+    inner: ISSN,
+}
+
+#[wasm_bindgen(js_class = ISSN)]
+impl JsISSN {
+    #[wasm_bindgen(constructor)]
+    pub fn new(identifier: &str) -> JsISSN {
+        JsISSN { inner: ISSN::new(identifier) }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn identifier(&self) -> String {
+        self.inner.identifier.clone()
+    }
+
+    pub fn valid(&self) -> bool {
+        self.inner.valid()
+    }
+
+    pub fn normalize(&self) -> Option<String> {
+        self.inner.normalize()
+    }
+
+    pub fn checkdigit(&self) -> String {
+        self.inner.checkdigit().into()
+    }
+
+    pub fn hyphenate(&self) -> Option<String> {
+        self.inner.display()
+    }
+}
+
+/// A Library of Congress Control Number
+#[wasm_bindgen(js_name = LCCN)]
+pub struct JsLCCN {
+    inner: LCCN,
+}
+
+#[wasm_bindgen(js_class = LCCN)]
+impl JsLCCN {
+    #[wasm_bindgen(constructor)]
+    pub fn new(identifier: &str) -> JsLCCN {
+        JsLCCN { inner: LCCN::new(identifier) }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn identifier(&self) -> String {
+        self.inner.identifier.clone()
+    }
+
+    pub fn valid(&self) -> bool {
+        self.inner.valid()
+    }
+
+    pub fn normalize(&self) -> Option<String> {
+        self.inner.normalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_wraps_the_identifier_types() {
+        let isbn = JsISBN::new("0-306-40615-2");
+        assert!(isbn.valid());
+        assert_eq!(isbn.checkdigit().unwrap(), "2");
+        assert_eq!(isbn.convert_to_13().unwrap(), "9780306406157");
+        assert_eq!(JsISBN::new("9780306406157").convert_to_10().unwrap(), "0306406152");
+        assert_eq!(JsISSN::new("1043-383x").checkdigit(), "X");
+        assert_eq!(JsLCCN::new("n78-890351").normalize().unwrap(), "n78890351");
+        assert_eq!(JsLCCN::new("bad").normalize(), None);
+    }
+}
@@ -0,0 +1,162 @@
+//! Checks every API against the outputs of the library_stdnums Ruby gem
+//!
+//! The fixtures are described in `tests/conformance/README.md`.
+use library_stdnums::{
+    batch, label, validate, CompactISBN, CompactISSN, CompactLCCN, Identifier, IdentifierKind, Normalize,
+    StandardNumber, Valid, ISBN, ISSN, LCCN,
+};
+
+struct Case<'a> {
+    line: usize,
+    kind: &'a str,
+    operation: &'a str,
+    input: &'a str,
+    expected: &'a str,
+}
+
+fn cases(fixture: &str) -> impl Iterator<Item = Case<'_>> {
+    fixture.lines().enumerate().filter(|(_, line)| !line.starts_with('#') && !line.is_empty()).map(|(index, line)| {
+        let fields: Vec<&str> = line.split('\t').collect();
+        // The gem's output, in the differences fixture, precedes this crate's
+        let expected = fields.last().copied().unwrap_or_default();
+        Case { line: index + 1, kind: fields[0], operation: fields[1], input: fields[2], expected }
+    })
+}
+
+fn option(value: Option<impl ToString>) -> String {
+    value.map_or_else(|| "nil".to_string(), |value| value.to_string())
+}
+
+fn byte(value: Option<u8>) -> String {
+    option(value.map(char::from))
+}
+
+// The output of every API that performs the operation, by name
+fn outputs(case: &Case) -> Vec<(&'static str, String)> {
+    let input = case.input;
+    // The validate module and the compact types don't clean Unicode input or remove labels
+    let plain = input.is_ascii() && label::parse(input).is_none();
+    let mut outputs = Vec::new();
+    match (case.kind, case.operation) {
+        ("isbn", "valid") => {
+            outputs.push(("ISBN::valid", ISBN::new(input).valid().to_string()));
+            outputs.push(("ISBN::parse", ISBN::parse(input).is_ok().to_string()));
+            outputs.push(("StandardNumber::valid", StandardNumber::new(IdentifierKind::ISBN, input).valid().to_string()));
+            outputs.push(("batch::validate", batch::validate::<ISBN, _>([input]).results[0].to_string()));
+            if plain {
+                outputs.push(("validate::isbn_valid", validate::isbn_valid(input.as_bytes()).to_string()));
+                outputs.push(("CompactISBN::new", CompactISBN::new(input).is_some().to_string()));
+            }
+        }
+        ("isbn", "normalize") => {
+            outputs.push(("ISBN::normalize", option(ISBN::new(input).normalize())));
+            outputs.push(("Identifier::canonical", option(ISBN::parse(input).ok().and_then(|isbn| isbn.canonical()))));
+            outputs.push(("StandardNumber::normalize", option(StandardNumber::new(IdentifierKind::ISBN, input).normalize())));
+            outputs.push(("batch::normalize", option(batch::normalize::<ISBN, _>([input]).results.remove(0))));
+            if plain {
+                outputs.push(("CompactISBN::new", option(CompactISBN::new(input))));
+            }
+        }
+        ("isbn", "checkdigit") => {
+            outputs.push(("ISBN::checkdigit", option(ISBN::new(input).checkdigit())));
+            if plain {
+                outputs.push(("validate::isbn_check_digit", byte(validate::isbn_check_digit(input.as_bytes()))));
+            }
+        }
+        ("isbn", "convert_to_13") => {
+            outputs.push(("ISBN::convert_to_13", option(ISBN::new(input).convert_to_13())));
+            if plain {
+                outputs.push(("validate::isbn_to_13", option(validate::isbn_to_13(input.as_bytes()))));
+            }
+        }
+        ("isbn", "convert_to_10") => {
+            outputs.push(("ISBN::convert_to_10", option(ISBN::new(input).convert_to_10().ok())));
+            if plain {
+                outputs.push(("validate::isbn_to_10", option(validate::isbn_to_10(input.as_bytes()))));
+            }
+        }
+        ("issn", "valid") => {
+            outputs.push(("ISSN::valid", ISSN::new(input).valid().to_string()));
+            outputs.push(("ISSN::parse", ISSN::parse(input).is_ok().to_string()));
+            outputs.push(("StandardNumber::valid", StandardNumber::new(IdentifierKind::ISSN, input).valid().to_string()));
+            outputs.push(("batch::validate", batch::validate::<ISSN, _>([input]).results[0].to_string()));
+            if plain {
+                outputs.push(("validate::issn_valid", validate::issn_valid(input.as_bytes()).to_string()));
+                outputs.push(("CompactISSN::new", CompactISSN::new(input).is_some().to_string()));
+            }
+        }
+        ("issn", "normalize") => {
+            outputs.push(("ISSN::normalize", option(ISSN::new(input).normalize())));
+            outputs.push(("Identifier::canonical", option(ISSN::parse(input).ok().and_then(|issn| issn.canonical()))));
+            outputs.push(("StandardNumber::normalize", option(StandardNumber::new(IdentifierKind::ISSN, input).normalize())));
+            outputs.push(("batch::normalize", option(batch::normalize::<ISSN, _>([input]).results.remove(0))));
+            if plain {
+                outputs.push(("validate::issn_normalize", option(validate::issn_normalize(input.as_bytes()))));
+                outputs.push(("CompactISSN::new", option(CompactISSN::new(input))));
+            }
+        }
+        ("issn", "checkdigit") => {
+            outputs.push(("ISSN::checkdigit", ISSN::new(input).checkdigit().to_string()));
+            if let Some(check_digit) = validate::issn_check_digit(input.as_bytes()).filter(|_| plain) {
+                outputs.push(("validate::issn_check_digit", byte(Some(check_digit))));
+            }
+        }
+        ("lccn", "valid") => {
+            outputs.push(("LCCN::valid", LCCN::new(input).valid().to_string()));
+            outputs.push(("LCCN::parse", LCCN::parse(input).is_ok().to_string()));
+            outputs.push(("StandardNumber::valid", StandardNumber::new(IdentifierKind::LCCN, input).valid().to_string()));
+            outputs.push(("batch::validate", batch::validate::<LCCN, _>([input]).results[0].to_string()));
+            if plain {
+                outputs.push(("validate::lccn_valid", validate::lccn_valid(input.as_bytes()).to_string()));
+            }
+        }
+        ("lccn", "normalize") => {
+            outputs.push(("LCCN::normalize", option(LCCN::new(input).normalize())));
+            outputs.push(("Identifier::canonical", option(LCCN::parse(input).ok().and_then(|lccn| lccn.canonical()))));
+            outputs.push(("StandardNumber::normalize", option(StandardNumber::new(IdentifierKind::LCCN, input).normalize())));
+            outputs.push(("batch::normalize", option(batch::normalize::<LCCN, _>([input]).results.remove(0))));
+            if plain {
+                outputs.push(("validate::lccn_normalize", option(validate::lccn_normalize(input.as_bytes()))));
+                // Serial numbers too large for the compact form are rejected
+                if let Some(compact) = CompactLCCN::new(input) {
+                    outputs.push(("CompactLCCN::new", compact.to_string()));
+                }
+            }
+        }
+        (kind, operation) => panic!("line {}: unknown operation {} {}", case.line, kind, operation),
+    }
+    outputs
+}
+
+fn check(name: &str, fixture: &str) {
+    let mut failures = Vec::new();
+    let mut checked = 0;
+    for case in cases(fixture) {
+        for (api, output) in outputs(&case) {
+            checked += 1;
+            if output != case.expected {
+                failures.push(format!(
+                    "{}:{}: {}({:?}) returned {}, expected {}",
+                    name, case.line, api, case.input, output, case.expected
+                ));
+            }
+        }
+    }
+    assert!(checked > 0, "{} has no cases", name);
+    assert!(failures.is_empty(), "{} of {} checks failed:\n{}", failures.len(), checked, failures.join("\n"));
+}
+
+#[test]
+fn it_matches_the_gem_specs() {
+    check("gem.tsv", include_str!("conformance/gem.tsv"));
+}
+
+#[test]
+fn it_matches_the_generated_corpus() {
+    check("generated.tsv", include_str!("conformance/generated.tsv"));
+}
+
+#[test]
+fn it_keeps_the_recorded_differences() {
+    check("differences.tsv", include_str!("conformance/differences.tsv"));
+}
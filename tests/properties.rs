@@ -0,0 +1,128 @@
+//! Property-based tests for the identifier types
+use library_stdnums::{
+    unicode, validate, CompactISBN, CompactISSN, CompactLCCN, Identifier, IdentifierKind, Normalize, ParseOptions,
+    StandardNumber, Valid, ISBN, ISSN, LCCN,
+};
+use proptest::prelude::*;
+
+// Strings that look like identifiers often enough to reach the deeper code paths
+fn identifier_like() -> impl Strategy<Value = String> {
+    prop_oneof![
+        any::<String>(),
+        "[0-9Xx -]{0,20}",
+        "[a-z]{0,3}[ -]?[0-9]{2,4}-?[0-9]{1,7}( /[A-Za-z/0-9]*)?",
+        "(ISBN|ISSN|LCCN|http://lccn.loc.gov/)?[:# ]{0,2}[0-9０-９٠-٩‐–-]{0,16}",
+    ]
+}
+
+fn isbn10() -> impl Strategy<Value = String> {
+    "[0-9]{9}".prop_map(|body| {
+        let check_digit = validate::isbn_check_digit(format!("{}0", body).as_bytes()).unwrap();
+        format!("{}{}", body, char::from(check_digit))
+    })
+}
+
+fn isbn13() -> impl Strategy<Value = String> {
+    "97[89][0-9]{9}".prop_map(|body| {
+        let check_digit = validate::isbn_check_digit(format!("{}0", body).as_bytes()).unwrap();
+        format!("{}{}", body, char::from(check_digit))
+    })
+}
+
+fn issn() -> impl Strategy<Value = String> {
+    "[0-9]{7}".prop_map(|body| format!("{}{}", body, ISSN::new(body.as_str()).checkdigit()))
+}
+
+proptest! {
+    #[test]
+    fn isbn10_round_trips_through_isbn13(isbn10 in isbn10()) {
+        let isbn13 = ISBN::new(isbn10.as_str()).convert_to_13().unwrap();
+        prop_assert_eq!(ISBN::new(isbn13).convert_to_10(), Ok(isbn10));
+    }
+
+    #[test]
+    fn search_variants_share_a_search_key(isbn13 in isbn13()) {
+        for variant in ISBN::new(isbn13.as_str()).search_variants() {
+            prop_assert_eq!(ISBN::search_key(&variant), Some(isbn13.clone()));
+        }
+    }
+
+    #[test]
+    fn generated_check_digits_validate(isbn10 in isbn10(), isbn13 in isbn13(), issn in issn()) {
+        prop_assert!(ISBN::new(isbn10).valid());
+        prop_assert!(ISBN::new(isbn13).valid());
+        prop_assert!(ISSN::new(issn).valid());
+    }
+
+    #[test]
+    fn a_changed_check_digit_is_invalid(isbn13 in isbn13(), change in 1..10u8) {
+        let mut changed = isbn13.into_bytes();
+        changed[12] = b'0' + (changed[12] - b'0' + change) % 10;
+        prop_assert!(!ISBN::new(String::from_utf8(changed).unwrap()).valid());
+    }
+
+    #[test]
+    fn hyphens_do_not_change_the_normalized_form(isbn13 in isbn13(), cut in 1..12usize) {
+        let hyphenated = format!("{}-{}", &isbn13[..cut], &isbn13[cut..]);
+        prop_assert_eq!(ISBN::new(hyphenated).normalize(), Some(isbn13));
+    }
+
+    #[test]
+    fn normalize_is_idempotent(input in identifier_like()) {
+        if let Some(normalized) = ISBN::new(input.as_str()).normalize() {
+            prop_assert_eq!(ISBN::new(normalized.as_str()).normalize(), Some(normalized));
+        }
+        if let Some(normalized) = ISSN::new(input.as_str()).normalize() {
+            prop_assert_eq!(ISSN::new(normalized.as_str()).normalize(), Some(normalized));
+        }
+        if let Some(normalized) = LCCN::new(input.as_str()).normalize() {
+            prop_assert_eq!(LCCN::new(normalized.as_str()).normalize(), Some(normalized));
+        }
+    }
+
+    #[test]
+    fn valid_agrees_with_normalize(input in identifier_like()) {
+        prop_assert_eq!(ISBN::new(input.as_str()).valid(), ISBN::new(input.as_str()).normalize().is_some());
+        prop_assert_eq!(ISSN::new(input.as_str()).valid(), ISSN::new(input.as_str()).normalize().is_some());
+        prop_assert_eq!(LCCN::new(input.as_str()).valid(), LCCN::new(input.as_str()).normalize().is_some());
+    }
+
+    #[test]
+    fn no_input_panics(input in identifier_like()) {
+        let isbn = ISBN::new(input.as_str());
+        let _ = (isbn.checkdigit(), isbn.convert_to_13(), isbn.convert_to_10(), isbn.parts(), isbn.display(), isbn.uri());
+        let issn = ISSN::new(input.as_str());
+        let _ = (issn.checkdigit(), issn.parts(), issn.display(), issn.uri());
+        let lccn = LCCN::new(input.as_str());
+        let _ = (lccn.parts(), lccn.display(), lccn.uri(), lccn.marc_010(), lccn.validate_strict_as_of(2024));
+        let _ = LCCN::from_marc_010(&input);
+        let _ = (CompactISBN::new(&input), CompactISSN::new(&input), CompactLCCN::new(&input));
+        let _ = StandardNumber::candidates(&input);
+        for kind in [IdentifierKind::ISBN, IdentifierKind::ISSN, IdentifierKind::LCCN] {
+            let _ = StandardNumber::parse(kind, &input);
+            let _ = ParseOptions::lenient().clean(kind, &input);
+        }
+        let _ = ISBN::parse_with(&input, &ParseOptions::lenient());
+        let _ = unicode::clean(&input);
+    }
+
+    #[test]
+    fn no_bytes_panic(input in any::<Vec<u8>>()) {
+        let _ = (validate::isbn_check_digit(&input), validate::isbn_valid(&input));
+        let _ = (validate::isbn_to_13(&input), validate::isbn_to_10(&input));
+        let _ = (validate::issn_check_digit(&input), validate::issn_normalize(&input));
+        let _ = validate::lccn_normalize(&input);
+    }
+
+    #[test]
+    fn compact_forms_round_trip(input in identifier_like()) {
+        if let Some(compact) = CompactISBN::new(&input) {
+            prop_assert_eq!(Some(compact.to_string()), ISBN::new(input.as_str()).normalize());
+            prop_assert_eq!(CompactISBN::from_u64(compact.get()), Some(compact));
+        }
+        if let Some(compact) = CompactLCCN::new(&input) {
+            prop_assert_eq!(Some(compact.to_string()), LCCN::new(input.as_str()).normalize());
+            prop_assert_eq!(CompactLCCN::from_u64(compact.get()), Some(compact));
+        }
+    }
+}